@@ -0,0 +1,519 @@
+use aoc_runner_internal::{Day, Part};
+use proc_macro2 as pm2;
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Error, Expr, Ident, Lit, Meta, Result, Token, UnOp};
+
+/// The parts a solution or a generator applies to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Parts {
+    One(Part),
+    Both,
+}
+
+/// The arguments of `#[aoc]` & `#[aoc_generator]`, e.g. `day1, part2, Fast, params(n = 2)`
+/// or `day = 1, part = 2, name = "Fast"`.
+pub(crate) struct Args {
+    pub day: Day,
+    pub part: Option<Parts>,
+    pub name: Option<String>,
+    pub params: Vec<(String, String)>,
+    /// Where the `params(...)` are declared
+    pub params_span: Option<pm2::Span>,
+}
+
+/// Options of `#[aoc]` & `#[aoc_generator]`, given as `key = value` or `key(...)`.
+const KEYS: &[&str] = &["day", "part", "name", "params"];
+
+/// Keys kept for options to come, rejected for now.
+const RESERVED: &[&str] = &["year", "timeout"];
+
+impl Args {
+    /// Parses either the positional form, `day1, part2, Fast`, or the explicit one,
+    /// `day = 1, part = 2, name = "Fast"`, followed by the options.
+    pub fn parse(args: pm2::TokenStream) -> Result<Args> {
+        let metas = Punctuated::<Meta, Token![,]>::parse_terminated.parse2(args)?;
+
+        let mut day = None;
+        let mut part = None;
+        let mut name = None;
+        let mut params = Vec::new();
+        let mut params_span = None;
+        let mut positional = 0;
+
+        for meta in metas {
+            let key = match &meta {
+                Meta::Path(path) => {
+                    let ident = path
+                        .get_ident()
+                        .ok_or_else(|| Error::new_spanned(path, "expected an identifier"))?;
+
+                    positional += 1;
+                    match positional {
+                        1 => set(&mut day, parse_day(ident)?, ident, "day")?,
+                        2 => set(&mut part, parse_part(ident)?, ident, "part")?,
+                        3 => set(&mut name, ident.to_string(), ident, "name")?,
+                        _ => {
+                            return Err(Error::new_spanned(
+                                ident,
+                                "unexpected argument, expected `day, part, name`",
+                            ))
+                        }
+                    }
+                    continue;
+                }
+                Meta::List(list) => &list.path,
+                Meta::NameValue(nv) => &nv.path,
+            };
+
+            let key = key_name(key)?;
+
+            match (key.to_string().as_str(), &meta) {
+                ("day", Meta::NameValue(nv)) => set(&mut day, day_value(&nv.value)?, &meta, "day")?,
+                ("part", Meta::NameValue(nv)) => {
+                    set(&mut part, part_value(&nv.value)?, &meta, "part")?
+                }
+                ("name", Meta::NameValue(nv)) => {
+                    set(&mut name, name_value(&nv.value)?, &meta, "name")?
+                }
+                ("params", Meta::List(list)) => {
+                    params_span = Some(list.span());
+                    params.extend(parse_params(list.tokens.clone())?);
+                }
+                ("params", _) => {
+                    return Err(Error::new_spanned(
+                        meta,
+                        "expected a list of params, e.g. `params(width = 101)`",
+                    ))
+                }
+                (key, Meta::List(_)) if KEYS.contains(&key) => {
+                    return Err(Error::new_spanned(
+                        meta,
+                        format!("expected `{} = ...`", key),
+                    ))
+                }
+                (key, _) if RESERVED.contains(&key) => {
+                    return Err(Error::new_spanned(
+                        meta.path(),
+                        format!("`{}` is reserved for a future version of aoc-runner", key),
+                    ))
+                }
+                (key, _) => {
+                    let message = match closest(key, KEYS) {
+                        Some(known) => {
+                            format!("unknown option `{}`, did you mean `{}`?", key, known)
+                        }
+                        None => format!(
+                            "unknown option `{}`, expected one of `{}`",
+                            key,
+                            KEYS.join("`, `")
+                        ),
+                    };
+                    return Err(Error::new_spanned(meta.path(), message));
+                }
+            }
+        }
+
+        let day = day.ok_or_else(|| {
+            Error::new(
+                pm2::Span::call_site(),
+                "missing day, e.g. `day1, part1` or `day = 1, part = 1`",
+            )
+        })?;
+
+        Ok(Args {
+            day,
+            part,
+            name,
+            params,
+            params_span,
+        })
+    }
+}
+
+/// Sets an argument given once, either by position or by key.
+fn set<T, S: quote::ToTokens>(arg: &mut Option<T>, value: T, tokens: S, key: &str) -> Result<()> {
+    if arg.is_some() {
+        return Err(Error::new_spanned(
+            tokens,
+            format!("`{}` is already given", key),
+        ));
+    }
+    *arg = Some(value);
+    Ok(())
+}
+
+fn key_name(path: &syn::Path) -> Result<&Ident> {
+    path.get_ident()
+        .ok_or_else(|| Error::new_spanned(path, format!("unknown option `{}`", path_name(path))))
+}
+
+fn path_name(path: &syn::Path) -> String {
+    quote::quote!(#path).to_string().replace(' ', "")
+}
+
+/// The known key closest to a mistyped one, if any is close enough.
+fn closest<'a>(found: &str, known: &[&'a str]) -> Option<&'a str> {
+    known
+        .iter()
+        .map(|k| (distance(found, k), *k))
+        .filter(|&(d, _)| d <= 2)
+        .min()
+        .map(|(_, k)| k)
+}
+
+/// The edit distance between two words.
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+
+        for (j, &cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if ca == cb {
+                prev
+            } else {
+                1 + prev.min(row[j]).min(current)
+            };
+            prev = current;
+        }
+    }
+
+    row[b.len()]
+}
+
+fn int_value(value: &Expr, expected: &str) -> Result<u8> {
+    match value {
+        Expr::Lit(syn::ExprLit {
+            lit: Lit::Int(i), ..
+        }) => i.base10_parse(),
+        _ => Err(Error::new_spanned(value, format!("expected {}", expected))),
+    }
+}
+
+fn day_value(value: &Expr) -> Result<Day> {
+    let day = int_value(value, "a day, e.g. `day = 1`")?;
+
+    if (1..=25).contains(&day) {
+        Ok(Day(day))
+    } else {
+        Err(Error::new_spanned(
+            value,
+            format!("day {} is not between 1 and 25", day),
+        ))
+    }
+}
+
+fn part_value(value: &Expr) -> Result<Parts> {
+    if let Expr::Path(path) = value {
+        if path.path.is_ident("both") {
+            return Ok(Parts::Both);
+        }
+    }
+
+    match int_value(value, "a part, e.g. `part = 1` or `part = both`")? {
+        part @ 1..=2 => Ok(Parts::One(Part(part))),
+        part => Err(Error::new_spanned(
+            value,
+            format!("part {} is not 1 or 2", part),
+        )),
+    }
+}
+
+fn name_value(value: &Expr) -> Result<String> {
+    match value {
+        Expr::Lit(syn::ExprLit {
+            lit: Lit::Str(name),
+            ..
+        }) => {
+            // Names are part of the generated modules & traits
+            syn::parse_str::<Ident>(&name.value())
+                .map(|name| name.to_string())
+                .map_err(|_| Error::new_spanned(name, "names must be valid identifiers"))
+        }
+        _ => Err(Error::new_spanned(
+            value,
+            "expected a name, e.g. `name = \"Fast\"`",
+        )),
+    }
+}
+
+/// The number in an identifier, e.g. `1` for `Day1` or `p1`.
+fn number(ident: &str) -> Option<u8> {
+    let digits: String = ident.chars().filter(char::is_ascii_digit).collect();
+    digits.parse().ok()
+}
+
+fn parse_day(ident: &Ident) -> Result<Day> {
+    let repr = ident.to_string();
+
+    if let Some(n) = repr.strip_prefix("day").and_then(|n| n.parse::<u8>().ok()) {
+        return if (1..=25).contains(&n) {
+            Ok(Day(n))
+        } else {
+            Err(Error::new_spanned(
+                ident,
+                format!("day {} is not between 1 and 25", n),
+            ))
+        };
+    }
+
+    let message = match number(&repr) {
+        _ if parse_part(ident).is_ok() => format!("missing day before `{}`", repr),
+        Some(n) if (1..=25).contains(&n) => format!(
+            "expected a day like `day1`, found `{}`, did you mean `day{}`?",
+            repr, n
+        ),
+        _ => format!("expected a day like `day1`, found `{}`", repr),
+    };
+
+    Err(Error::new_spanned(ident, message))
+}
+
+fn parse_part(ident: &Ident) -> Result<Parts> {
+    let repr = ident.to_string();
+
+    match repr.as_str() {
+        "part1" => return Ok(Parts::One(Part(1))),
+        "part2" => return Ok(Parts::One(Part(2))),
+        "both" => return Ok(Parts::Both),
+        _ => {}
+    }
+
+    let message = match number(&repr) {
+        Some(n @ 1..=2) => format!(
+            "expected `part1`, `part2` or `both`, found `{}`, did you mean `part{}`?",
+            repr, n
+        ),
+        _ if repr.eq_ignore_ascii_case("both") => format!(
+            "expected `part1`, `part2` or `both`, found `{}`, did you mean `both`?",
+            repr
+        ),
+        _ => format!("expected `part1`, `part2` or `both`, found `{}`", repr),
+    };
+
+    Err(Error::new_spanned(ident, message))
+}
+
+fn parse_params(params: pm2::TokenStream) -> Result<Vec<(String, String)>> {
+    let params = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated.parse2(params)?;
+
+    params
+        .into_iter()
+        .map(|param| {
+            let key = param
+                .path
+                .get_ident()
+                .ok_or_else(|| Error::new_spanned(&param.path, "param names must be identifiers"))?
+                .to_string();
+
+            Ok((key, param_value(&param.value)?))
+        })
+        .collect()
+}
+
+fn param_value(value: &Expr) -> Result<String> {
+    match value {
+        Expr::Lit(syn::ExprLit { lit, .. }) => match lit {
+            Lit::Str(s) => Ok(s.value()),
+            Lit::Char(c) => Ok(c.value().to_string()),
+            Lit::Int(i) => Ok(i.base10_digits().to_string()),
+            Lit::Float(f) => Ok(f.base10_digits().to_string()),
+            Lit::Bool(b) => Ok(b.value.to_string()),
+            _ => Err(Error::new_spanned(lit, "unsupported param value")),
+        },
+        Expr::Unary(syn::ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => Ok(format!("-{}", param_value(expr)?)),
+        _ => Err(Error::new_spanned(value, "param values must be literals")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    fn error(args: pm2::TokenStream) -> String {
+        match Args::parse(args) {
+            Ok(_) => panic!("expected an error"),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    #[test]
+    fn parse_positional() {
+        let args = Args::parse(quote!(day1, part2, Fast)).unwrap();
+        assert_eq!(args.day, Day(1));
+        assert_eq!(args.part, Some(Parts::One(Part(2))));
+        assert_eq!(args.name.as_deref(), Some("Fast"));
+
+        let args = Args::parse(quote!(day25)).unwrap();
+        assert_eq!(args.day, Day(25));
+        assert_eq!(args.part, None);
+        assert_eq!(args.name, None);
+
+        let args = Args::parse(quote!(day7, both)).unwrap();
+        assert_eq!(args.part, Some(Parts::Both));
+    }
+
+    #[test]
+    fn parse_keys() {
+        let args = Args::parse(quote!(day = 2, part = 1, name = "x")).unwrap();
+        assert_eq!(args.day, Day(2));
+        assert_eq!(args.part, Some(Parts::One(Part(1))));
+        assert_eq!(args.name.as_deref(), Some("x"));
+
+        let args = Args::parse(quote!(part = both, day = 7)).unwrap();
+        assert_eq!(args.day, Day(7));
+        assert_eq!(args.part, Some(Parts::Both));
+
+        let args = Args::parse(quote!(day3, part1, name = "Fast")).unwrap();
+        assert_eq!(args.name.as_deref(), Some("Fast"));
+    }
+
+    #[test]
+    fn parse_params() {
+        let args = Args::parse(quote!(
+            day14,
+            part1,
+            params(width = 101, shift = -3, label = "a", fast = true)
+        ))
+        .unwrap();
+        let params: Vec<_> = args
+            .params
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        assert_eq!(
+            params,
+            [
+                ("width", "101"),
+                ("shift", "-3"),
+                ("label", "a"),
+                ("fast", "true")
+            ]
+        );
+        assert!(args.params_span.is_some());
+
+        assert_eq!(
+            error(quote!(day14, part1, params = 1)),
+            "expected a list of params, e.g. `params(width = 101)`"
+        );
+        assert_eq!(
+            error(quote!(day14, part1, params(width = [1]))),
+            "param values must be literals"
+        );
+    }
+
+    #[test]
+    fn rejects_days_and_parts_out_of_range() {
+        assert_eq!(error(quote!(day0)), "day 0 is not between 1 and 25");
+        assert_eq!(
+            error(quote!(day26, part1)),
+            "day 26 is not between 1 and 25"
+        );
+        assert_eq!(error(quote!(day = 26)), "day 26 is not between 1 and 25");
+        assert_eq!(error(quote!(day = "1")), "expected a day, e.g. `day = 1`");
+        assert_eq!(
+            error(quote!(day1, part3)),
+            "expected `part1`, `part2` or `both`, found `part3`"
+        );
+        assert_eq!(error(quote!(day = 1, part = 3)), "part 3 is not 1 or 2");
+    }
+
+    #[test]
+    fn suggests_fixes() {
+        assert_eq!(
+            error(quote!(Day2, part1)),
+            "expected a day like `day1`, found `Day2`, did you mean `day2`?"
+        );
+        assert_eq!(
+            error(quote!(day2, Part1)),
+            "expected `part1`, `part2` or `both`, found `Part1`, did you mean `part1`?"
+        );
+        assert_eq!(
+            error(quote!(day2, Both)),
+            "expected `part1`, `part2` or `both`, found `Both`, did you mean `both`?"
+        );
+        assert_eq!(error(quote!(part1)), "missing day before `part1`");
+        assert_eq!(
+            error(quote!(dya = 1, part = 1)),
+            "unknown option `dya`, did you mean `day`?"
+        );
+        assert_eq!(
+            error(quote!(day1, part1, color = "red")),
+            "unknown option `color`, expected one of `day`, `part`, `name`, `params`"
+        );
+    }
+
+    #[test]
+    fn rejects_reserved_keys() {
+        assert_eq!(
+            error(quote!(day1, part1, year = 2024)),
+            "`year` is reserved for a future version of aoc-runner"
+        );
+        assert_eq!(
+            error(quote!(day = 1, part = 1, timeout = 10)),
+            "`timeout` is reserved for a future version of aoc-runner"
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_arguments() {
+        assert_eq!(
+            error(quote!(part = 1)),
+            "missing day, e.g. `day1, part1` or `day = 1, part = 1`"
+        );
+        assert_eq!(error(quote!(day1, day = 2)), "`day` is already given");
+        assert_eq!(error(quote!(day(1))), "expected `day = ...`");
+        assert_eq!(
+            error(quote!(day1, part1, name = "not a name")),
+            "names must be valid identifiers"
+        );
+        assert_eq!(
+            error(quote!(day1, part1, Fast, Extra)),
+            "unexpected argument, expected `day, part, name`"
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_keys() {
+        assert_eq!(
+            error(quote!(day = 1, part = 1, part = 2)),
+            "`part` is already given"
+        );
+        assert_eq!(
+            error(quote!(day = 1, part = "1")),
+            "expected a part, e.g. `part = 1` or `part = both`"
+        );
+        assert_eq!(error(quote!(day = 1, part(1))), "expected `part = ...`");
+        assert_eq!(
+            error(quote!(day = 1, part = 1, name = Fast)),
+            "expected a name, e.g. `name = \"Fast\"`"
+        );
+        assert_eq!(
+            error(quote!(day = 1, prat = 1)),
+            "unknown option `prat`, did you mean `part`?"
+        );
+        assert_eq!(
+            error(quote!(day = 1, part = 1, year(2024))),
+            "`year` is reserved for a future version of aoc-runner"
+        );
+    }
+
+    #[test]
+    fn distances() {
+        assert_eq!(distance("day", "day"), 0);
+        assert_eq!(distance("dya", "day"), 2);
+        assert_eq!(distance("parts", "part"), 1);
+        assert_eq!(closest("nmae", KEYS), Some("name"));
+        assert_eq!(closest("color", KEYS), None);
+    }
+}
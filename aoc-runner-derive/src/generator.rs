@@ -1,59 +1,180 @@
-use crate::types::Generator;
-use crate::utils;
+use crate::args::{Args, Parts};
+use crate::types::{fn_path, Generator};
+use crate::utils::{self, to_camelcase, to_snakecase};
 use crate::AOC_RUNNER;
 use aoc_runner_internal::{DayPart, Part};
 use proc_macro as pm;
+use proc_macro2 as pm2;
+use quote::quote;
 use syn::*;
 
 pub fn generator_impl(args: pm::TokenStream, input: pm::TokenStream) -> pm::TokenStream {
-    let (day, part, name) = utils::extract_meta(args);
-    let day = day
-        .to_string()
-        .parse()
-        .expect("generators must have defined day");
-    let part = part.and_then(|p| p.to_string().parse().ok());
-    let name = name.map(|i| i.to_string());
+    let input = parse_macro_input!(input as ItemFn);
 
-    let input_cloned = input.clone();
+    let generator =
+        register_generator(args.into(), &input.sig, None).unwrap_or_else(utils::compile_error);
 
-    let input = parse_macro_input!(input as ItemFn);
+    pm::TokenStream::from(quote! {
+        #input
+
+        #generator
+    })
+}
 
-    let fn_name = input.sig.ident;
-    let out_t = if let ReturnType::Type(_, p) = input.sig.output {
+/// Registers a generator, `self_ty` being the type of the `impl` block of associated functions.
+///
+/// Returns the items to emit next to it (or to its `impl` block).
+pub(crate) fn register_generator(
+    args: pm2::TokenStream,
+    sig: &Signature,
+    self_ty: Option<&Type>,
+) -> Result<pm2::TokenStream> {
+    let Args {
+        day,
+        part,
+        name,
+        params_span,
+        ..
+    } = Args::parse(args)?;
+
+    if let Some(span) = params_span {
+        return Err(Error::new(
+            span,
+            "params are declared on solutions, not on generators",
+        ));
+    }
+
+    let fn_name = &sig.ident;
+    let out_t = if let ReturnType::Type(_, p) = &sig.output {
         p
     } else {
-        panic!("cannot find output type for {}", fn_name)
+        return Err(Error::new(
+            sig.paren_token.span.close(),
+            format!("generator `{}` must return its output", fn_name),
+        ));
+    };
+    let out_t = match self_ty {
+        Some(self_ty) => utils::replace_self(out_t, self_ty),
+        None => (**out_t).clone(),
     };
 
-    let (special_type, out_t) = if let Some((ty, inner)) = utils::extract_result(&out_t) {
-        (Some(ty), Box::new(inner))
-    } else {
-        (None, out_t)
+    let generator = Generator::new(fn_name, self_ty);
+    let fn_generator = fn_path(fn_name, self_ty);
+    let generator_name = generator.get_name();
+
+    let parts = match part {
+        Some(Parts::One(part)) => vec![part],
+        Some(Parts::Both) | None => vec![Part(1), Part(2)],
+    };
+    let dps: Vec<_> = parts
+        .into_iter()
+        .map(|part| DayPart {
+            day,
+            part,
+            name: name.clone(),
+        })
+        .collect();
+
+    // A function can be the generator of several days, each registering its own module
+    let mod_name = utils::to_ident(
+        &format!("__aoc_gen_{}_{}", to_snakecase(&dps[0]), generator_name),
+        fn_name.span(),
+    );
+
+    // Outputs borrowing from the input are stored along with it, through a `Family`
+    // naming their type for any lifetime
+    let (family, stored_t, generate, lend, bound) = match utils::borrowed_output(&out_t) {
+        Some(borrowed_t) => (
+            quote! {
+                pub(super) struct Family;
+
+                impl aoc_runner::kind::Family for Family {
+                    type Output<'a> = #borrowed_t;
+                }
+            },
+            quote! { aoc_runner::kind::Borrowed<Family> },
+            quote! {
+                // Through a closure, so generators of `impl<'a>` blocks work for any lifetime
+                let output = aoc_runner::kind::Borrowed::<Family>::new(input, |input| #fn_generator(input));
+                output.with(|output| output.aoc_kind().check(output))?;
+            },
+            quote! {
+                let output = generated.downcast_ref::<aoc_runner::kind::Borrowed<Family>>().unwrap();
+                output.with(|output| f(LendsAs::lent(output)))
+            },
+            quote! { <Family as aoc_runner::kind::Family>::Output<'a> },
+        ),
+        None => (
+            quote! {},
+            quote! { #out_t },
+            quote! {
+                let output = #fn_generator(std::borrow::Borrow::borrow(&input));
+                let output = (&output).aoc_kind().checked(output)?;
+            },
+            quote! {
+                f(LendsAs::lent(generated.downcast_ref::<#out_t>().unwrap()))
+            },
+            quote! { #out_t },
+        ),
+    };
+
+    // The solvers are bound to the generator by `aoc_lib!`, through the marker types of its parts
+    let impls = dps.iter().map(|dp| {
+        let marker = to_camelcase(dp);
+
+        quote! {
+            impl aoc_runner::kind::Generator for crate::aoc_factory::generators::#marker {
+                const NAME: Option<&'static str> = Some(#generator_name);
+                const ID: Option<&'static str> = Some(module_path!());
+
+                fn generate(input: ArcStr) -> Result<Generated, Box<dyn std::error::Error>> {
+                    #generate
+
+                    Ok(std::sync::Arc::new(output))
+                }
+
+                fn accepts(generated: &Generated) -> bool {
+                    generated.is::<#stored_t>()
+                }
+            }
+
+            impl<A: Takes, K> Lend<A, K> for crate::aoc_factory::generators::#marker
+            where
+                for<'a> #bound: LendsAs<A::Input<'a>, K>,
+            {
+                fn lend<R>(generated: &Generated, f: impl for<'a> FnOnce(&'a A::Input<'a>) -> R) -> R {
+                    #lend
+                }
+            }
+        }
+    });
+
+    let expanded = quote! {
+        #[doc(hidden)]
+        #[allow(unused_imports, non_snake_case)]
+        mod #mod_name {
+            use super::*;
+            use aoc_runner::{ArcStr, Generated};
+            use aoc_runner::kind::{Lend, LendsAs, OptionKind, PlainKind, ResultKind, Takes};
+
+            #family
+
+            #(#impls)*
+        }
     };
 
     AOC_RUNNER.with(|map| {
         let mut map = map
             .borrow_mut()
-            .expect("failed to borrow shared map from generator");
-
-        let mut register = |p: Part| {
-            let runner = map
-                .entry(DayPart {
-                    day,
-                    part: p,
-                    name: name.clone(),
-                })
-                .or_default();
-            runner.with_generator(Generator::new(&fn_name, &out_t, special_type));
-        };
-
-        if let Some(p) = part {
-            register(p);
-        } else {
-            register(Part(1));
-            register(Part(2));
+            .map_err(|e| Error::new_spanned(fn_name, e))?;
+
+        for dp in dps {
+            map.entry(dp)
+                .or_default()
+                .with_generator(generator.clone())
+                .map_err(|e| Error::new_spanned(fn_name, e))?;
         }
-    });
 
-    input_cloned
+        Ok(expanded)
+    })
 }
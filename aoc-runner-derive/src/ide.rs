@@ -0,0 +1,78 @@
+//! Expansion by IDEs (rust-analyzer, IntelliJ, RLS), which run the macros in a process of their
+//! own, on every edit of the crate, item by item.
+//!
+//! It's free of side effects : solutions aren't collected and `target/aoc` isn't written, that's
+//! left to the actual build. Flagged items are kept as is, with their spans, so go-to-definition,
+//! hover & diagnostics keep working on them, and mistakes in the arguments are still reported.
+
+use crate::args::Args;
+use crate::out;
+use crate::utils;
+use proc_macro as pm;
+use proc_macro2 as pm2;
+use quote::quote;
+use std::env;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Executables expanding proc macros outside of rustc.
+const EXPANDERS: &[&str] = &[
+    "rust-analyzer-proc-macro-srv",
+    "rust-analyzer",
+    "intellij-rust-native-helper",
+    "rls",
+];
+
+/// Whether the macros are expanded by an IDE rather than by rustc.
+pub(crate) fn is_ide() -> bool {
+    static IS_IDE: OnceLock<bool> = OnceLock::new();
+
+    *IS_IDE.get_or_init(|| {
+        let is_expander = |path: &Path| {
+            path.file_stem()
+                .and_then(|stem| stem.to_str())
+                .is_some_and(|stem| EXPANDERS.contains(&stem))
+        };
+
+        // The RLS ran the macros in-process, but was started by cargo under its own name
+        env::current_exe().is_ok_and(|exe| is_expander(&exe))
+            || env::var_os("CARGO").is_some_and(|cargo| is_expander(Path::new(&cargo)))
+    })
+}
+
+/// `#[aoc]` & `#[aoc_generator]`, on a function or an `impl` block.
+pub(crate) fn attribute(args: pm::TokenStream, input: pm::TokenStream) -> pm::TokenStream {
+    let input = pm2::TokenStream::from(input);
+
+    // `impl` blocks take no argument, their functions are expanded on their own
+    let error = if args.is_empty() {
+        None
+    } else {
+        Args::parse(args.into()).err().map(utils::compile_error)
+    };
+
+    pm::TokenStream::from(quote! {
+        #[allow(dead_code)]
+        #input
+
+        #error
+    })
+}
+
+/// `aoc_lib!`, whose items are only used by the generated code.
+pub(crate) fn lib(input: pm::TokenStream) -> pm::TokenStream {
+    let error = out::parse_lib_infos(input).err().map(utils::compile_error);
+
+    pm::TokenStream::from(quote! { #error })
+}
+
+/// `aoc_main!`, declaring the `main` of the binary.
+pub(crate) fn main(input: pm::TokenStream) -> pm::TokenStream {
+    let error = out::parse_main_infos(input).err().map(utils::compile_error);
+
+    pm::TokenStream::from(quote! {
+        fn main() {}
+
+        #error
+    })
+}
@@ -7,18 +7,19 @@ extern crate proc_macro2;
 extern crate quote;
 extern crate syn;
 
+mod args;
 mod generator;
+mod ide;
 mod map;
+mod methods;
 mod out;
 mod runner;
 mod types;
 mod utils;
 
+use crate::ide::is_ide;
 use crate::map::Map;
-use crate::utils::is_rls;
 use proc_macro as pm;
-use proc_macro2 as pm2;
-use quote::quote;
 
 thread_local! {
     static AOC_RUNNER: Map = Map::new();
@@ -33,26 +34,70 @@ thread_local! {
 /// You can also add a custom name to the function :
 /// `#[aoc(day1, part1, Bytes)]`, it's useful to have multiple solutions to a given day & part and compare them !
 ///
+/// The same can be written with keys : `#[aoc(day = 1, part = 1, name = "Bytes")]`.
+/// `year` & `timeout` are reserved for future options.
+///
 /// The function must take a single parameter : a `&str` or a `&[u8]`, unless you use a [generator]
 /// and return any type implementing `Display`.
 ///
+/// With a generator, the function can also take its output by value or as `&mut`, e.g. to run
+/// a simulation in place : it then gets a clone on each call (not measured by `cargo aoc bench`).
+///
+/// ## Both parts at once
+///
+/// When part 2 reuses most of the work of part 1, a single function can solve both with
+/// `#[aoc(day7, both)]`, returning a pair of answers (or a struct implementing
+/// `aoc_runner::IntoAnswers`). It's registered for each part, and runs once when both are asked.
+///
+/// ## Params
+///
+/// Constants differing between the examples and the real input can be declared with
+/// `#[aoc(day14, part1, params(width = 101, height = 103))]`, the function then takes
+/// a `&aoc_runner::Params` as second argument : `let width: i32 = params.get("width");`.
+///
+/// They can be overridden at runtime, with `cargo aoc --param width=11` or `Solution::run_with`.
+///
+/// ## Associated functions
+///
+/// Attributes can't add items inside an `impl` block, so flag the block itself with `#[aoc]`
+/// to use its associated functions as generators or solutions :
+///
+/// ```ignore
+/// #[aoc]
+/// impl Node {
+///     #[aoc_generator(day8)]
+///     fn parse(input: &str) -> Option<Node> { ... }
+///
+///     #[aoc(day8, part1)]
+///     fn checksum(&self) -> usize { ... }
+/// }
+/// ```
+///
+/// Methods taking `self` are called on the generator output. Blocks of concrete types only,
+/// like `impl Grid<u8>` or `impl<'a> Words<'a>`, not `impl<T> Grid<T>`. Solutions can also be
+/// defined in nested modules of a day.
+///
 /// ## Results & Options
 ///
 /// Since 0.2.0, you can output `Result` & `Option` from solution function, with the following constraints :
-///  - the output type must be named `Result` or `Option`, `type CustomResult<T> = Result<T, CustomError>;` cannot be used in return position.
 ///  - the first generic parameter must implement `Display`
 ///  - for `Result`s, the error must implement `Into<std::error::Error>`
 ///
-/// You still can use a path before the `Result`/`Option`, like this : `std::io::Result<i32>`
+/// They are recognized by their type, so aliases like `type CustomResult<T> = Result<T, CustomError>;`
+/// work too, as well as `impl Display` outputs.
 ///
 /// [generator]: attr.aoc_generator.html
 pub fn aoc(args: pm::TokenStream, input: pm::TokenStream) -> pm::TokenStream {
-    if is_rls() {
-        let input: pm2::TokenStream = input.into();
-        return pm::TokenStream::from(quote! {
-            #[allow(unused)]
-            #input
-        });
+    if is_ide() {
+        return ide::attribute(args, input);
+    }
+
+    if methods::is_registered(&args) {
+        return input;
+    }
+
+    if let Ok(item) = syn::parse::<syn::ItemImpl>(input.clone()) {
+        return methods::methods_impl(args, item);
     }
 
     runner::runner_impl(args, input)
@@ -71,26 +116,31 @@ pub fn aoc(args: pm::TokenStream, input: pm::TokenStream) -> pm::TokenStream {
 ///
 /// The function must take a single parameter : a `&str` or a `&[u8]`, and output any sized type.
 ///
+/// The output can borrow from the input, e.g. `Vec<&str>` or `Vec<Line<'a>>`, to avoid copying it :
+/// the input is then kept alive along with the output. Lifetimes of structs must be spelled out
+/// (`Line<'_>`, not `Line`).
+///
 /// The corresponding solutions now take any parameter for which `Borrow` is implemented.
 ///
 /// ## Results & Options
 ///
 /// Since 0.2.0, you can output `Result` & `Option` from generator function, with the following constraints :
-///  - the output type must be named `Result` or `Option`, `type CustomResult<T> = Result<T, CustomError>;` cannot be used in return position.
 ///  - for `Result`s, the error must implement `Into<std::error::Error>`
 ///
-/// You still can use a path before the `Result`/`Option`, like this : `std::io::Result<i32>`
+/// They are recognized by their type, so aliases like `type CustomResult<T> = Result<T, CustomError>;`
+/// work too.
 ///
 /// ## Note
-/// A generator must be declared before it's solutions.
+/// A generator can be declared anywhere in the crate, before or after its solutions, e.g. in a
+/// shared `parse.rs` module : `aoc_lib!` binds each solution to the generator of its day & part.
 ///
 pub fn aoc_generator(args: pm::TokenStream, input: pm::TokenStream) -> pm::TokenStream {
-    if is_rls() {
-        let input: pm2::TokenStream = input.into();
-        return pm::TokenStream::from(quote! {
-            #[allow(unused)]
-            #input
-        });
+    if is_ide() {
+        return ide::attribute(args, input);
+    }
+
+    if methods::is_registered(&args) {
+        return input;
     }
 
     generator::generator_impl(args, input)
@@ -104,8 +154,8 @@ pub fn aoc_generator(args: pm::TokenStream, input: pm::TokenStream) -> pm::Token
 /// ## Usage
 /// `aoc_lib! { year = 2018 }`
 pub fn aoc_lib(input: pm::TokenStream) -> pm::TokenStream {
-    if is_rls() {
-        return pm::TokenStream::new();
+    if is_ide() {
+        return ide::lib(input);
     }
 
     out::lib_impl(input)
@@ -120,9 +170,15 @@ pub fn aoc_lib(input: pm::TokenStream) -> pm::TokenStream {
 /// `aoc_main` has 2 forms :
 ///  - as a standalone binary : `aoc_main! { year = 2018 }`
 ///  - as a link to a library : `aoc_main! { lib = advent_of_code_2018 }` (you must had `extern crate advent_of_code_2018;` before)
+///
+/// The binary runs every solution, unless told otherwise by its arguments, e.g.
+/// `cargo run --release -- --day 5 --part 2 --name Fnv --input example.txt` (`--stdin` to read
+/// the input from stdin, `--format plain|json`, `--repeat N`, `--param key=value`, `--jobs N` to
+/// run independent days on N threads, `--alloc` to count allocations with
+/// `aoc_runner::alloc::Counting` as the global allocator).
 pub fn aoc_main(input: pm::TokenStream) -> pm::TokenStream {
-    if is_rls() {
-        return pm::TokenStream::from(quote! { fn main() {} });
+    if is_ide() {
+        return ide::main(input);
     }
 
     out::main_impl(input)
@@ -2,6 +2,7 @@ use crate::types::Runner;
 use aoc_runner_internal::DayPart;
 use std::cell::{Cell, Ref, RefCell, RefMut};
 use std::collections::HashMap;
+use std::fmt;
 
 pub(crate) type InnerMap = HashMap<DayPart, Runner>;
 
@@ -10,6 +11,17 @@ pub enum MapError {
     AlreadyConsumed,
 }
 
+impl fmt::Display for MapError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MapError::AlreadyConsumed => write!(
+                f,
+                "solutions are collected by `aoc_lib!` or `aoc_main!`, which was already used"
+            ),
+        }
+    }
+}
+
 pub struct Map {
     inner: RefCell<InnerMap>,
     consumed: Cell<bool>,
@@ -23,7 +35,7 @@ impl Map {
         }
     }
 
-    pub(crate) fn consume(&self) -> Result<Ref<InnerMap>, MapError> {
+    pub(crate) fn consume(&self) -> Result<Ref<'_, InnerMap>, MapError> {
         if self.consumed.replace(true) {
             Err(MapError::AlreadyConsumed)
         } else {
@@ -31,7 +43,7 @@ impl Map {
         }
     }
 
-    pub(crate) fn borrow_mut(&self) -> Result<RefMut<InnerMap>, MapError> {
+    pub(crate) fn borrow_mut(&self) -> Result<RefMut<'_, InnerMap>, MapError> {
         if self.consumed.take() {
             Err(MapError::AlreadyConsumed)
         } else {
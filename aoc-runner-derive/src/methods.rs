@@ -0,0 +1,82 @@
+use crate::generator::register_generator;
+use crate::runner::register_solver;
+use crate::utils;
+use proc_macro as pm;
+use proc_macro2 as pm2;
+use quote::quote;
+use syn::*;
+
+/// Arguments of the `#[aoc]` & `#[aoc_generator]` attributes already handled by their `impl` block.
+pub const REGISTERED: &str = "__aoc_registered";
+
+pub fn is_registered(args: &pm::TokenStream) -> bool {
+    args.to_string() == REGISTERED
+}
+
+/// `#[aoc]` on an `impl` block: registers its associated functions flagged with
+/// `#[aoc_generator]` or `#[aoc]`, which can't emit items of their own inside the block.
+pub fn methods_impl(args: pm::TokenStream, mut input: ItemImpl) -> pm::TokenStream {
+    let mut generated = Vec::new();
+
+    // On error, the functions are still marked as registered, so they don't raise more errors
+    let error = if !args.is_empty() {
+        Some(Error::new_spanned(
+            pm2::TokenStream::from(args),
+            "`#[aoc]` on an impl block takes no argument, flag its functions instead",
+        ))
+    } else {
+        // Functions are called on a concrete type, lifetimes are elided
+        input
+            .generics
+            .params
+            .iter()
+            .find(|param| !matches!(param, GenericParam::Lifetime(_)))
+            .map(|param| {
+                Error::new_spanned(
+                    param,
+                    "`#[aoc]` doesn't support impl blocks generic over types, use a concrete type, e.g. `impl Grid<u8>`",
+                )
+            })
+    };
+    let failed = error.is_some();
+    generated.extend(error.map(utils::compile_error));
+
+    let self_ty = (*input.self_ty).clone();
+
+    for item in &mut input.items {
+        let method = match item {
+            ImplItem::Fn(method) => method,
+            _ => continue,
+        };
+
+        for attr in &mut method.attrs {
+            let register = match attr.path().segments.last() {
+                Some(segment) if segment.ident == "aoc_generator" => register_generator,
+                Some(segment) if segment.ident == "aoc" => register_solver,
+                _ => continue,
+            };
+
+            let registered = match &attr.meta {
+                _ if failed => Ok(quote! {}),
+                Meta::List(list) => register(list.tokens.clone(), &method.sig, Some(&self_ty)),
+                meta => Err(Error::new_spanned(meta, "missing day, e.g. `day1, part1`")),
+            };
+            generated.push(registered.unwrap_or_else(utils::compile_error));
+
+            // The attribute is kept, so its import is still used, but does nothing more
+            let span = attr.path().segments.last().unwrap().ident.span();
+            let marker = Ident::new(REGISTERED, span);
+            attr.meta = Meta::List(MetaList {
+                path: attr.path().clone(),
+                delimiter: MacroDelimiter::Paren(token::Paren(span)),
+                tokens: quote! { #marker },
+            });
+        }
+    }
+
+    pm::TokenStream::from(quote! {
+        #input
+
+        #(#generated)*
+    })
+}
@@ -1,64 +1,66 @@
 use crate::map::InnerMap;
-use crate::utils::{to_camelcase, to_input, to_snakecase};
+use crate::utils::{self, to_camelcase, to_snakecase};
 use crate::AOC_RUNNER;
 use aoc_runner_internal::{DayParts, DayPartsBuilder};
 use proc_macro as pm;
 use proc_macro2 as pm2;
 use quote::quote;
-use std::error;
+use syn::{Error, Expr, ExprLit, Lit, MetaNameValue, Result};
 
 #[derive(Debug)]
-struct LibInfos {
+pub(crate) struct LibInfos {
     year: u32,
 }
 
 #[derive(Debug)]
-enum MainInfos {
+pub(crate) enum MainInfos {
     Ref { lib: pm2::Ident },
     Standalone { year: u32 },
 }
 
 pub fn lib_impl(input: pm::TokenStream) -> pm::TokenStream {
-    let infos = parse_lib_infos(input).expect("failed to parse lib infos");
+    let expanded = parse_lib_infos(input).and_then(|infos| {
+        AOC_RUNNER.with(|map| {
+            let map = map
+                .consume()
+                .map_err(|e| Error::new(pm2::Span::call_site(), e))?;
 
-    AOC_RUNNER.with(|map| {
-        let map = map.consume().expect("failed to consume map from lib");
+            let year = infos.year;
 
-        let year = infos.year;
+            write_infos(&map, year)?;
 
-        write_infos(&map, year).expect("failed to write infos from lib");
+            Ok(headers(&map, year))
+        })
+    });
 
-        pm::TokenStream::from(headers(&map, year))
-    })
+    pm::TokenStream::from(expanded.unwrap_or_else(utils::compile_error))
 }
 
 pub fn main_impl(input: pm::TokenStream) -> pm::TokenStream {
-    let infos = parse_main_infos(input).expect("failed to parse main infos");
-
-    AOC_RUNNER.with(|map| {
-        let map = map.consume().expect("failed to consume map from main");
-
-        let expanded = match infos {
-            MainInfos::Ref { lib } => {
-                let infos = read_infos().expect("failed to read infos from ref main");
-                body(&infos, Some(lib))
-            }
-            MainInfos::Standalone { year } => {
-                let infos =
-                    write_infos(&map, year).expect("failed to write infos from standalone main");
-                let headers = headers(&map, year);
-                let body = body(&infos, None);
-
-                quote! {
-                    #headers
-
-                    #body
+    let expanded = parse_main_infos(input).and_then(|infos| {
+        AOC_RUNNER.with(|map| {
+            let map = map
+                .consume()
+                .map_err(|e| Error::new(pm2::Span::call_site(), e))?;
+
+            Ok(match infos {
+                MainInfos::Ref { lib } => body(Some(lib)),
+                MainInfos::Standalone { year } => {
+                    write_infos(&map, year)?;
+                    let headers = headers(&map, year);
+                    let body = body(None);
+
+                    quote! {
+                        #headers
+
+                        #body
+                    }
                 }
-            }
-        };
+            })
+        })
+    });
 
-        pm::TokenStream::from(expanded)
-    })
+    pm::TokenStream::from(expanded.unwrap_or_else(utils::compile_error))
 }
 
 fn headers(map: &InnerMap, year: u32) -> pm2::TokenStream {
@@ -77,6 +79,38 @@ fn headers(map: &InnerMap, year: u32) -> pm2::TokenStream {
         })
         .collect();
 
+    // Generators implement `Generator` & `Lend` for the markers of their parts
+    let generators: pm2::TokenStream = map
+        .iter()
+        .filter(|(_, runner)| runner.generator.is_some())
+        .map(|(dp, _)| {
+            let camel = to_camelcase(dp);
+
+            quote! { pub struct #camel; }
+        })
+        .collect();
+
+    // Solvers use the generator of their part, or the unnamed one of their day & part
+    let inputs: pm2::TokenStream = map
+        .iter()
+        .filter(|(_, runner)| runner.solver.is_some())
+        .map(|(dp, runner)| {
+            let camel = to_camelcase(dp);
+            let def = dp.without_name();
+
+            let generator = if runner.generator.is_some() {
+                quote! { super::generators::#camel }
+            } else if map.get(&def).is_some_and(|r| r.generator.is_some()) {
+                let def = to_camelcase(&def);
+                quote! { super::generators::#def }
+            } else {
+                quote! { aoc_runner::kind::Raw }
+            };
+
+            quote! { pub type #camel = #generator; }
+        })
+        .collect();
+
     quote! {
         pub use self::aoc_factory::*;
 
@@ -86,114 +120,48 @@ fn headers(map: &InnerMap, year: u32) -> pm2::TokenStream {
             use std::error::Error;
 
             #[doc(hidden)]
-            pub static YEAR : u32 = #year;
+            pub const YEAR : u32 = #year;
+
+            // The crates generated by `cargo aoc` run the solutions through it, as they are
+            // registered in this instance of `aoc-runner`, whatever its version or source.
+            // Its items are re-exported rather than the crate, which may be a private `extern crate`
+            #[doc(hidden)]
+            pub mod __aoc_runner {
+                pub use aoc_runner::*;
+            }
 
             #[doc(hidden)]
             pub struct Factory();
 
             #traits_impl
-        }
-    }
-}
-
-fn body(infos: &DayParts, lib: Option<pm2::Ident>) -> pm2::TokenStream {
-    let mut days: Vec<_> = infos.iter().map(|dp| dp.day).collect();
-    days.sort();
-    days.dedup();
-
-    let inputs: pm2::TokenStream = days
-        .into_iter()
-        .map(|d| {
-            let name = to_input(d);
-            let input = format!("../input/{}/day{}.txt", infos.year, d.0);
-
-            quote! { let #name = ArcStr::from(include_str!(#input)); }
-        })
-        .collect();
 
-    let body : pm2::TokenStream = infos.iter().map(|dp| {
-        let identifier = to_snakecase(dp);
-        let (pattern, err) = if let Some(n) = &dp.name {
-            (
-                format!(
-                    "Day {} - Part {} - {}: {{}}\n\tgenerator: {{:?}},\n\trunner: {{:?}}\n",
-                    dp.day.0, dp.part.0, n
-                ),
-                format! (
-                    "Day {} - Part {} - {}: FAILED while {{}}:\n{{:#?}}\n",
-                    dp.day.0, dp.part.0, n
-                )
-            )
-        } else {
-            (
-                format!(
-                    "Day {} - Part {}: {{}}\n\tgenerator: {{:?}},\n\trunner: {{:?}}\n",
-                    dp.day.0, dp.part.0
-                ),
-                format! (
-                    "Day {} - Part {}: FAILED while {{}}:\n{{:#?}}\n",
-                    dp.day.0, dp.part.0
-                )
-            )
-        };
-
-        let input = to_input(dp.day);
-
-        quote! {
-            {
-                let start_time = Instant::now();
-
-                match Factory::#identifier(#input.clone()) {
-                    Ok(runner) => {
-                        let inter_time = Instant::now();
-
-                        match runner.try_run() {
-                            Ok(result) => {
-                                let final_time = Instant::now();
-                                println!(#pattern, result, (inter_time - start_time), (final_time - inter_time));
-                            },
-                            Err(e) => eprintln!(#err, "running", e)
-                        }
-                    },
-                    Err(e) => eprintln!(#err, "generating", e)
-                }
+            pub(crate) mod generators {
+                #generators
             }
-        }
-    }).collect();
-
-    if let Some(lib) = lib {
-        quote! {
-            use #lib::*;
-
-            fn main() {
-                use aoc_runner::ArcStr;
-                use std::time::{Duration, Instant};
 
+            pub(crate) mod inputs {
                 #inputs
-
-                println!("Advent of code {}", YEAR);
-
-                #body
             }
         }
-    } else {
-        quote! {
-            fn main() {
-                use aoc_runner::ArcStr;
-                use std::time::{Duration, Instant};
+    }
+}
 
+fn body(lib: Option<pm2::Ident>) -> pm2::TokenStream {
+    let lib = lib.map(|lib| quote! { use #lib::*; });
 
-                #inputs
+    quote! {
+        #lib
 
-                println!("Advent of code {}", YEAR);
+        fn main() {
+            // Inputs are read at runtime, the solutions to run are selected by the arguments
+            let inputs = aoc_runner::Inputs::new(concat!(env!("CARGO_MANIFEST_DIR"), "/input"));
 
-                #body
-            }
+            aoc_runner::cli::main(YEAR, inputs)
         }
     }
 }
 
-fn write_infos(map: &InnerMap, year: u32) -> Result<DayParts, Box<dyn error::Error>> {
+fn write_infos(map: &InnerMap, year: u32) -> Result<DayParts> {
     let mut day_parts = map
         .iter()
         .filter_map(|(dp, runner)| {
@@ -208,82 +176,60 @@ fn write_infos(map: &InnerMap, year: u32) -> Result<DayParts, Box<dyn error::Err
 
     day_parts.sort();
 
-    day_parts.save()?;
+    day_parts.save().map_err(|e| {
+        Error::new(
+            pm2::Span::call_site(),
+            format!("failed to save the solutions in target/aoc: {}", e),
+        )
+    })?;
 
     Ok(day_parts)
 }
 
-fn read_infos() -> Result<DayParts, Box<dyn error::Error>> {
-    DayParts::load()
+fn parse_infos(infos: pm::TokenStream, usage: &str) -> Result<MetaNameValue> {
+    syn::parse(infos).map_err(|e| Error::new(e.span(), format!("{}, expected {}", e, usage)))
 }
 
-fn parse_lib_infos(infos: pm::TokenStream) -> Result<LibInfos, ()> {
-    let tokens: Vec<_> = infos.into_iter().collect();
-
-    if let pm::TokenTree::Ident(i) = tokens.first().ok_or(())? {
-        if i.to_string() != "year" {
-            return Err(());
-        }
-    } else {
-        return Err(());
-    }
-
-    if let pm::TokenTree::Punct(p) = tokens.get(1).ok_or(())? {
-        if p.as_char() != '=' {
-            return Err(());
-        }
-    } else {
-        return Err(());
-    }
-
-    if let pm::TokenTree::Literal(l) = tokens.get(2).ok_or(())? {
-        let repr = l.to_string();
-
-        let year = repr.parse().map_err(|_| ())?;
-
-        Ok(LibInfos { year })
-    } else {
-        Err(())
+fn parse_year(value: &Expr) -> Result<u32> {
+    match value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(year),
+            ..
+        }) => year.base10_parse(),
+        _ => Err(Error::new_spanned(value, "expected a year, e.g. `2018`")),
     }
 }
 
-fn parse_main_infos(infos: pm::TokenStream) -> Result<MainInfos, ()> {
-    let tokens: Vec<_> = infos.into_iter().collect();
+pub(crate) fn parse_lib_infos(infos: pm::TokenStream) -> Result<LibInfos> {
+    let infos = parse_infos(infos, "`year = 2018`")?;
 
-    if let pm::TokenTree::Punct(p) = tokens.get(1).ok_or(())? {
-        if p.as_char() != '=' {
-            return Err(());
-        }
-    } else {
-        return Err(());
+    if !infos.path.is_ident("year") {
+        return Err(Error::new_spanned(infos.path, "expected `year = 2018`"));
     }
 
-    if let pm::TokenTree::Ident(i) = tokens.first().ok_or(())? {
-        let ty = i.to_string();
+    Ok(LibInfos {
+        year: parse_year(&infos.value)?,
+    })
+}
 
-        Ok(match ty.as_ref() {
-            "year" => {
-                let repr = if let pm::TokenTree::Literal(l) = tokens.get(2).ok_or(())? {
-                    l.to_string()
-                } else {
-                    return Err(());
-                };
+pub(crate) fn parse_main_infos(infos: pm::TokenStream) -> Result<MainInfos> {
+    let infos = parse_infos(infos, "`lib = your_crate` or `year = 2018`")?;
 
-                let year = repr.parse().map_err(|_| ())?;
-                MainInfos::Standalone { year }
-            }
-            "lib" => {
-                let lib = if let pm::TokenTree::Ident(i) = tokens.get(2).ok_or(())? {
-                    pm2::Ident::new(&i.to_string(), pm2::Span::from(i.span()))
-                } else {
-                    return Err(());
-                };
-
-                MainInfos::Ref { lib }
-            }
-            _ => return Err(()),
+    if infos.path.is_ident("year") {
+        Ok(MainInfos::Standalone {
+            year: parse_year(&infos.value)?,
         })
+    } else if infos.path.is_ident("lib") {
+        match &infos.value {
+            Expr::Path(path) if path.path.get_ident().is_some() => Ok(MainInfos::Ref {
+                lib: path.path.get_ident().unwrap().clone(),
+            }),
+            value => Err(Error::new_spanned(value, "expected a crate name")),
+        }
     } else {
-        Err(())
+        Err(Error::new_spanned(
+            infos.path,
+            "expected `lib = your_crate` or `year = 2018`",
+        ))
     }
 }
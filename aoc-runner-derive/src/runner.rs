@@ -1,196 +1,422 @@
-use crate::types::{Generator, Solver, SpecialType};
-use crate::utils::{self, extract_result, to_camelcase, to_snakecase};
+use crate::args::{Args, Parts};
+use crate::types::{fn_path, Solver};
+use crate::utils::{self, to_camelcase, to_snakecase};
 use crate::AOC_RUNNER;
-use aoc_runner_internal::DayPart;
+use aoc_runner_internal::{DayPart, Part};
 use proc_macro as pm;
 use proc_macro2 as pm2;
-use quote::quote;
+use quote::{quote, quote_spanned};
 use syn::*;
 
 pub fn runner_impl(args: pm::TokenStream, input: pm::TokenStream) -> pm::TokenStream {
-    let (day, part, name) = utils::extract_meta(args);
-    let day = day
-        .to_string()
-        .parse()
-        .expect("runners must have a defined day");
-    let part = part
-        .expect("runners must have a defined part")
-        .to_string()
-        .parse()
-        .expect("runners must have a defined part");
-    let name = name.map(|i| i.to_string());
-
-    let dp = DayPart { day, part, name };
-
     let input = parse_macro_input!(input as ItemFn);
 
-    let original_fn = input.clone();
+    let solution =
+        register_solver(args.into(), &input.sig, None).unwrap_or_else(utils::compile_error);
 
-    let fn_name = input.sig.ident;
-    let out_t = if let ReturnType::Type(_, p) = input.sig.output {
-        p
-    } else {
-        panic!()
-    };
+    pm::TokenStream::from(quote! {
+        #input
 
-    let (special_type, out_t) = if let Some((ty, inner)) = extract_result(&out_t) {
-        (Some(ty), Box::new(inner))
-    } else {
-        (None, out_t)
+        #solution
+    })
+}
+
+/// Registers a solver, `self_ty` being the type of the `impl` block of associated functions.
+///
+/// Returns the module to emit next to it (or to its `impl` block).
+pub(crate) fn register_solver(
+    args: pm2::TokenStream,
+    sig: &Signature,
+    self_ty: Option<&Type>,
+) -> Result<pm2::TokenStream> {
+    let Args {
+        day,
+        part,
+        name,
+        params,
+        params_span,
+    } = Args::parse(args)?;
+
+    // Solvers of both parts are registered for each part, picking their answer
+    let parts = match part {
+        Some(Parts::One(part)) => vec![(part, None)],
+        Some(Parts::Both) => vec![(Part(1), Some(0)), (Part(2), Some(1))],
+        None => {
+            return Err(Error::new(
+                pm2::Span::call_site(),
+                format!(
+                    "missing part, e.g. `#[aoc(day{0}, part1)]` or `#[aoc(day{0}, both)]`",
+                    day.0
+                ),
+            ))
+        }
     };
 
-    let def = AOC_RUNNER.with(|map| {
-        let mut map = map
-            .borrow_mut()
-            .expect("failed to borrow shared map from runner");
+    let fn_name = &sig.ident;
+    let fn_path = fn_path(fn_name, self_ty);
 
-        let dp = dp.clone();
-        let def = dp.without_name();
+    if let ReturnType::Default = sig.output {
+        return Err(Error::new(
+            sig.paren_token.span.close(),
+            format!("solution `{}` must return its answer", fn_name),
+        ));
+    }
 
-        if !map.contains_key(&dp) && map.contains_key(&def) {
-            let mut val = map[&def].clone();
-            val.solver = None;
-            map.insert(dp.clone(), val);
+    // Params are given to the solvers taking a second argument
+    let with_params = match sig.inputs.len() {
+        1 => false,
+        2 => true,
+        0 => {
+            return Err(Error::new(
+                sig.paren_token.span.join(),
+                format!("solution `{}` must take the input", fn_name),
+            ))
         }
+        _ => {
+            return Err(Error::new_spanned(
+                &sig.inputs,
+                format!(
+                    "solution `{}` must take the input, and optionally its params",
+                    fn_name
+                ),
+            ))
+        }
+    };
 
-        let runner = map.entry(dp).or_default();
-
-        runner.with_solver(Solver::new(&fn_name, &out_t, special_type));
+    if let (Some(span), false) = (params_span, with_params) {
+        return Err(Error::new(
+            span,
+            format!(
+                "solution `{}` declares params, but doesn't take them as second argument",
+                fn_name
+            ),
+        ));
+    }
 
-        let derive = build_derive(runner.solver.as_ref().unwrap(), runner.generator.as_ref());
+    let (input_kind, input_t) = solver_input(fn_name, &sig.inputs[0], self_ty)?;
 
-        if let Some(generator) = &runner.generator {
-            let gen_out_t = &generator.get_out_t();
+    let location = quote_spanned! { fn_name.span() =>
+        Location {
+            file: file!(),
+            line: line!(),
+            column: column!(),
+        }
+    };
 
-            quote! {
-                pub struct RunnerStruct {
-                    input: #gen_out_t,
-                    output: PhantomData<#out_t>,
+    let params: Vec<_> = params.iter().map(|(k, v)| quote! { (#k, #v) }).collect();
+
+    parts
+        .into_iter()
+        .map(|(part, answer)| {
+            let dp = DayPart {
+                day,
+                part,
+                name: name.clone(),
+            };
+
+            AOC_RUNNER.with(|map| -> Result<_> {
+                let mut map = map
+                    .borrow_mut()
+                    .map_err(|e| Error::new_spanned(fn_name, e))?;
+
+                map.entry(dp.clone())
+                    .or_default()
+                    .with_solver(Solver::new(fn_name, self_ty))
+                    .map_err(|e| {
+                        Error::new_spanned(
+                            fn_name,
+                            format!("{}, give this one a name, e.g. `#[aoc(day{}, part{}, Fast)]`", e, day.0, part.0),
+                        )
+                    })
+            })?;
+
+            let mod_name = to_snakecase(&dp);
+            let trait_name = to_camelcase(&dp);
+
+            // The generator is bound by `aoc_lib!`, once every generator is known
+            let generator = quote! { crate::aoc_factory::inputs::#trait_name };
+
+            let derive = build_derive(
+                &fn_path,
+                &generator,
+                &input_t,
+                input_kind,
+                with_params,
+                answer,
+            );
+
+            let day = dp.day.0;
+            let part = dp.part.0;
+            let name = option_str(dp.name.as_deref());
+            let both = answer.is_some();
+
+            Ok(quote! {
+                #[allow(unused_imports)]
+                mod #mod_name {
+                    use super::*;
+                    use aoc_runner::{inventory, Answer, ArcStr, Generated, Location, Params, Runner, Solution};
+                    use aoc_runner::kind::{Generator, OptionKind, PlainKind, ResultKind, Takes};
+                    use crate::{Factory, YEAR, #trait_name};
+
+                    const PARAMS: &[(&str, &str)] = &[#(#params),*];
+
+                    impl #trait_name for Factory {
+                        fn #mod_name(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+                            runner(&<#generator as Generator>::generate(input)?, Params::from(PARAMS))
+                        }
+                    }
+
+                    inventory::submit! {
+                        Solution::new(
+                            YEAR,
+                            #day,
+                            #part,
+                            #name,
+                            #both,
+                            <#generator as Generator>::NAME,
+                            <#generator as Generator>::ID,
+                            #location,
+                            PARAMS,
+                            <#generator as Generator>::generate,
+                            runner,
+                        )
+                    }
+
+                    #derive
                 }
+            })
+        })
+        .collect()
+}
 
-                #derive
-            }
-        } else {
-            quote! {
-                pub struct RunnerStruct {
-                    input: ArcStr,
-                    output: PhantomData<#out_t>,
-                }
+fn option_str(s: Option<&str>) -> pm2::TokenStream {
+    if let Some(s) = s {
+        quote! { Some(#s) }
+    } else {
+        quote! { None }
+    }
+}
 
-                #derive
-            }
-        }
-    });
+/// How a solver takes the output of its generator.
+#[derive(Clone, Copy, PartialEq)]
+enum InputKind {
+    /// `&T`, borrowed from the shared output
+    Ref,
+    /// `&mut T`, borrowed from a clone
+    RefMut,
+    /// `T`, cloned
+    Owned,
+}
 
-    let mod_name = to_snakecase(&dp);
-    let trait_name = to_camelcase(&dp);
+/// How a solver takes its input, and its type once borrowed, e.g. `[&'a str]` for `&[&str]`.
+fn solver_input(fn_name: &Ident, arg: &FnArg, self_ty: Option<&Type>) -> Result<(InputKind, Type)> {
+    let ty = match (arg, self_ty) {
+        (FnArg::Typed(arg), _) => &arg.ty,
+        (FnArg::Receiver(receiver), Some(_)) => &receiver.ty,
+        (FnArg::Receiver(receiver), None) => {
+            return Err(Error::new_spanned(
+                receiver,
+                format!(
+                    "`{}` takes self, flag its impl block with `#[aoc]` too",
+                    fn_name
+                ),
+            ))
+        }
+    };
+    let ty = match self_ty {
+        Some(self_ty) => utils::replace_self(ty, self_ty),
+        None => (**ty).clone(),
+    };
 
-    pm::TokenStream::from(quote! {
-        #original_fn
-
-        #[allow(unused_imports)]
-        mod #mod_name {
-            use super::*;
-            use aoc_runner::{ArcStr, Runner};
-            use std::marker::PhantomData;
-            use std::error::Error;
-            use std::fmt::Display;
-            use std::borrow::Borrow;
-            use crate::{Factory, #trait_name};
-
-            impl #trait_name for Factory {
-                fn #mod_name(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>> {
-                    Ok(Box::new( RunnerStruct::try_gen(input)? ))
-                }
-            }
+    let (input_kind, input_t) = match ty {
+        Type::Reference(r) if r.mutability.is_some() => (InputKind::RefMut, *r.elem),
+        Type::Reference(r) => (InputKind::Ref, *r.elem),
+        ty => (InputKind::Owned, ty),
+    };
 
-            #def
-        }
-    })
+    // Named for any lifetime of the generator output
+    Ok((
+        input_kind,
+        utils::borrowed_output(&input_t).unwrap_or(input_t),
+    ))
 }
 
-fn build_derive(solver: &Solver, generator: Option<&Generator>) -> pm2::TokenStream {
-    let fn_runner = solver.get_name();
-
-    // Build the output, possibly using quasi-quotation
-    let input = if let Some(generator) = generator {
-        let fn_generator = generator.get_name();
+fn build_derive(
+    fn_runner: &pm2::TokenStream,
+    generator: &pm2::TokenStream,
+    input_t: &Type,
+    input_kind: InputKind,
+    with_params: bool,
+    answer: Option<usize>,
+) -> pm2::TokenStream {
+    // The output of the generator is lent to the solver as its input type,
+    // `Option` & `Result` are unwrapped by their type, not by their name.
+    let lend = |input: pm2::TokenStream, body: pm2::TokenStream| {
         quote! {
-            input: #fn_generator(input.borrow())
+            aoc_runner::kind::lend::<#generator, __SolverInput, _, _>(&self.input, |#input| {
+                #body
+            })
         }
+    };
+
+    let params_arg = if with_params {
+        quote! { , &self.params }
     } else {
-        quote! {
-            input
-        }
+        quote! {}
     };
 
-    let gen = if let Some(t) = generator.and_then(|g| g.special_type) {
-        let input = match t {
-            SpecialType::Result => quote! { #input? },
-            SpecialType::Option => quote! { #input.ok_or("generator produce no value")? },
-        };
+    // Solvers of both parts return a pair (or a struct) of answers, which isn't `Display`
+    let black_box = if answer.is_some() {
+        quote! { black_box(&aoc_runner::kind::Opaque(&output)) }
+    } else {
+        quote! { black_box( (&output).aoc_kind().display(&output) ) }
+    };
 
-        quote! {
-            fn gen(input: ArcStr) -> Self {
-                Self::try_gen(input).expect("failed to generate input")
-            }
+    // Solvers taking their input by value or mutably get their own copy on each call
+    let call = match input_kind {
+        InputKind::Ref => quote! {
+            let output = #fn_runner(input #params_arg);
+        },
+        InputKind::RefMut => quote! {
+            let mut input = std::borrow::ToOwned::to_owned(input);
+            let output = #fn_runner(std::borrow::BorrowMut::borrow_mut(&mut input) #params_arg);
+        },
+        InputKind::Owned => quote! {
+            let output = #fn_runner(std::borrow::ToOwned::to_owned(input) #params_arg);
+        },
+    };
 
-            fn try_gen(input: ArcStr) -> Result<Self, Box<dyn Error>> {
-                Ok( RunnerStruct {
-                    #input,
-                    output: PhantomData,
-                } )
-            }
-        }
-    } else {
-        quote! {
-            fn gen(input: ArcStr) -> Self {
-                RunnerStruct {
-                    #input,
-                    output: PhantomData,
+    // ... which is prepared outside of the measure when benchmarking
+    // (copies borrowing from the generator output can't outlive `lend`, so they are measured)
+    let borrows = utils::borrowed_output(input_t).is_some();
+    let batched = match input_kind {
+        InputKind::Ref => quote! {},
+        _ if borrows => quote! {},
+        InputKind::RefMut | InputKind::Owned => {
+            let batched_call = if input_kind == InputKind::RefMut {
+                quote! {
+                    let mut input = input;
+                    let output = #fn_runner(std::borrow::BorrowMut::borrow_mut(&mut input) #params_arg);
+                }
+            } else {
+                quote! {
+                    let output = #fn_runner(input #params_arg);
+                }
+            };
+
+            let setup = lend(
+                quote! { input },
+                quote! {
+                    let input: Box<dyn std::any::Any> = Box::new(std::borrow::ToOwned::to_owned(input));
+                    input
+                },
+            );
+            let bench_batched = lend(
+                quote! { like },
+                quote! {
+                    let input = aoc_runner::kind::downcast_as(like, input);
+                    #batched_call
+                    #black_box
+                },
+            );
+
+            quote! {
+                fn setup(&self) -> Box<dyn std::any::Any> {
+                    #setup
+                }
+
+                fn bench_batched(&self, input: Box<dyn std::any::Any>, black_box: fn(&dyn std::fmt::Display)) {
+                    #bench_batched
                 }
             }
         }
     };
 
-    let run = if let Some(t) = solver.special_type {
-        let runner = match t {
-            SpecialType::Result => quote! { #fn_runner(self.input.borrow())? },
-            SpecialType::Option => {
-                quote! { #fn_runner(self.input.borrow()).ok_or("runner produce no value")? }
-            }
-        };
-
+    let (try_run, try_run_both) = match answer {
+        Some(answer) => {
+            let answer = Index::from(answer);
+
+            let try_run = lend(
+                quote! { input },
+                quote! {
+                    #call
+                    (&output).aoc_kind().answers(output).map(|answers| answers.#answer)
+                },
+            );
+            let try_run_both = lend(
+                quote! { input },
+                quote! {
+                    #call
+                    (&output).aoc_kind().answers(output)
+                },
+            );
+
+            (
+                try_run,
+                quote! {
+                    fn try_run_both(&self) -> Result<(Answer, Answer), Box<dyn std::error::Error>> {
+                        #try_run_both
+                    }
+                },
+            )
+        }
+        None => {
+            let try_run = lend(
+                quote! { input },
+                quote! {
+                    #call
+                    (&output).aoc_kind().answer(output)
+                },
+            );
+
+            (try_run, quote! {})
+        }
+    };
+    let bench = lend(
+        quote! { input },
         quote! {
-            fn run(&self) -> Box<dyn Display> {
-                self.try_run().expect("failed to run")
-            }
+            #call
+            #black_box
+        },
+    );
 
-            fn try_run(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-                Ok( Box::new( #runner ) )
-            }
+    quote! {
+        struct __SolverInput;
 
-            fn bench(&self, black_box: fn(&dyn Display)) {
-                black_box( &#fn_runner(self.input.borrow()).unwrap() )
-            }
+        impl Takes for __SolverInput {
+            type Input<'a> = #input_t;
         }
-    } else {
-        quote! {
-            fn run(&self) -> Box<dyn Display> {
-                Box::new( #fn_runner(self.input.borrow()) )
-            }
 
-            fn bench(&self, black_box: fn(&dyn Display)) {
-                black_box( &#fn_runner(self.input.borrow()) )
+        fn runner(generated: &Generated, params: Params) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+            if !<#generator as Generator>::accepts(generated) {
+                return Err("unexpected generator output".into());
             }
+
+            Ok(Box::new(RunnerStruct { input: generated.clone(), params }))
+        }
+
+        pub struct RunnerStruct {
+            input: Generated,
+            #[allow(dead_code)]
+            params: Params,
         }
-    };
 
-    quote! {
         impl Runner for RunnerStruct {
-            #gen
+            fn run(&self) -> Answer {
+                self.try_run().expect("failed to run")
+            }
+
+            fn try_run(&self) -> Result<Answer, Box<dyn std::error::Error>> {
+                #try_run
+            }
+
+            #try_run_both
+
+            fn bench(&self, black_box: fn(&dyn std::fmt::Display)) {
+                #bench
+            }
 
-            #run
+            #batched
         }
     }
 }
@@ -1,4 +1,4 @@
-use proc_macro as pm;
+use crate::utils;
 use proc_macro2 as pm2;
 use quote::quote;
 use syn;
@@ -10,76 +10,72 @@ pub(crate) struct Runner {
 }
 
 impl Runner {
-    pub fn with_generator(&mut self, generator: Generator) {
-        if self.solver.is_some() {
-            panic!("Generators must be defined before solutions: {:?}", self);
+    pub fn with_generator(&mut self, generator: Generator) -> Result<(), String> {
+        if let Some(defined) = &self.generator {
+            return Err(format!(
+                "a generator is already defined: `{}`",
+                defined.get_name()
+            ));
         }
-        if self.generator.is_some() {
-            panic!("A generator is already defined: {:?}", self);
-        }
-        self.generator = Some(generator)
+        self.generator = Some(generator);
+        Ok(())
     }
 
-    pub fn with_solver(&mut self, solver: Solver) {
-        if self.solver.is_some() {
-            panic!("A solution is already defined: {:?}", self);
+    pub fn with_solver(&mut self, solver: Solver) -> Result<(), String> {
+        if let Some(defined) = &self.solver {
+            return Err(format!("a solution is already defined: `{}`", defined.name));
         }
-        self.solver = Some(solver)
+        self.solver = Some(solver);
+        Ok(())
     }
 }
 
-#[derive(Copy, Clone, Debug)]
-pub(crate) enum SpecialType {
-    Result,
-    Option,
-}
-
 #[derive(Clone, Debug)]
 pub(crate) struct Generator {
     name: String,
-    out_t: String,
-    pub special_type: Option<SpecialType>,
 }
 
 impl Generator {
-    pub fn new(
-        name: &syn::Ident,
-        out_t: &syn::Type,
-        special_type: Option<SpecialType>,
-    ) -> Generator {
+    pub fn new(fn_name: &syn::Ident, self_ty: Option<&syn::Type>) -> Generator {
         Generator {
-            name: name.to_string(),
-            out_t: quote! { #out_t }.to_string(),
-            special_type,
+            name: fn_display(fn_name, self_ty),
         }
     }
 
-    pub fn get_name(&self) -> syn::Ident {
-        syn::Ident::new(&self.name, pm::Span::call_site().into())
+    /// The name of the generator, e.g. `parse` or `Day1::parse`.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// The path calling a function, `self_ty` being the type of the `impl` block of associated functions.
+pub(crate) fn fn_path(fn_name: &syn::Ident, self_ty: Option<&syn::Type>) -> pm2::TokenStream {
+    match self_ty {
+        Some(self_ty) => {
+            let self_ty = utils::elide_lifetimes(self_ty);
+            quote! { <#self_ty>::#fn_name }
+        }
+        None => quote! { #fn_name },
     }
+}
 
-    pub fn get_out_t(&self) -> pm2::TokenStream {
-        self.out_t.parse().expect("failed to parse generator type")
+pub(crate) fn fn_display(fn_name: &syn::Ident, self_ty: Option<&syn::Type>) -> String {
+    match self_ty {
+        Some(self_ty) => format!("{}::{}", quote! { #self_ty }, fn_name).replace(' ', ""),
+        None => fn_name.to_string(),
     }
 }
 
 #[derive(Clone, Debug)]
 pub(crate) struct Solver {
+    // Only shown in error messages
     name: String,
-    //    out_t: String,
-    pub special_type: Option<SpecialType>,
 }
 
 impl Solver {
-    pub fn new(name: &syn::Ident, _out_t: &syn::Type, special_type: Option<SpecialType>) -> Solver {
+    pub fn new(fn_name: &syn::Ident, self_ty: Option<&syn::Type>) -> Solver {
         Solver {
-            name: name.to_string(),
-            //            out_t: quote! { #out_t }.to_string(),
-            special_type,
+            name: fn_display(fn_name, self_ty),
         }
     }
-
-    pub fn get_name(&self) -> syn::Ident {
-        syn::Ident::new(&self.name, pm::Span::call_site().into())
-    }
 }
@@ -1,54 +1,123 @@
-use aoc_runner_internal::{Day, DayPart};
+use aoc_runner_internal::DayPart;
 use proc_macro as pm;
+use proc_macro2 as pm2;
+use quote::quote_spanned;
 use syn;
-use types::SpecialType;
-
-pub(crate) fn extract_meta(
-    args: pm::TokenStream,
-) -> (syn::Ident, Option<syn::Ident>, Option<syn::Ident>) {
-    let mut idents = args.into_iter().filter_map(|a| {
-        if let pm::TokenTree::Ident(_) = a {
-            Some(a.into())
-        } else {
-            None
+
+/// The error as `compile_error!` invocations, like `Error::into_compile_error` without its
+/// `::core::` prefix, which crates of the 2015 edition can't resolve.
+pub(crate) fn compile_error(error: syn::Error) -> pm2::TokenStream {
+    error
+        .into_iter()
+        .map(|error| {
+            let message = error.to_string();
+            quote_spanned!(error.span()=> compile_error! { #message })
+        })
+        .collect()
+}
+
+/// Names every lifetime of a generator output `'a`, e.g. `Vec<&str>` becomes `Vec<&'a str>`,
+/// or returns `None` if it doesn't borrow anything.
+pub(crate) fn borrowed_output(ty: &syn::Type) -> Option<syn::Type> {
+    use syn::visit_mut::{self, VisitMut};
+
+    struct Lifetimes {
+        found: bool,
+    }
+
+    impl Lifetimes {
+        fn named(&mut self, span: pm2::Span) -> syn::Lifetime {
+            self.found = true;
+            syn::Lifetime::new("'a", span)
+        }
+    }
+
+    impl VisitMut for Lifetimes {
+        fn visit_lifetime_mut(&mut self, lifetime: &mut syn::Lifetime) {
+            if lifetime.ident != "static" {
+                *lifetime = self.named(lifetime.span());
+            }
         }
-    });
 
-    let day: pm::TokenStream = idents.next().expect("Couldn't find day");
-    let day: syn::Ident = syn::parse(day).expect("failed to parse day");
+        fn visit_type_reference_mut(&mut self, reference: &mut syn::TypeReference) {
+            if reference.lifetime.is_none() {
+                reference.lifetime = Some(self.named(reference.and_token.span));
+            }
+            visit_mut::visit_type_reference_mut(self, reference);
+        }
+
+        // Elided lifetimes of `fn(&str)` and `Fn(&str)` have their own scope
+        fn visit_type_bare_fn_mut(&mut self, _: &mut syn::TypeBareFn) {}
+
+        fn visit_parenthesized_generic_arguments_mut(
+            &mut self,
+            _: &mut syn::ParenthesizedGenericArguments,
+        ) {
+        }
+    }
 
-    let part = idents.next().and_then(|i| syn::parse(i).ok());
-    let name = idents.next().and_then(|i| syn::parse(i).ok());
+    let mut ty = ty.clone();
+    let mut lifetimes = Lifetimes { found: false };
+    lifetimes.visit_type_mut(&mut ty);
 
-    (day, part, name)
+    if lifetimes.found {
+        Some(ty)
+    } else {
+        None
+    }
 }
 
-pub(crate) fn extract_result(ty: &syn::Type) -> Option<(SpecialType, syn::Type)> {
-    use syn::*;
-
-    if let Type::Path(TypePath {
-        path: Path { segments: s, .. },
-        ..
-    }) = ty
-    {
-        if let Some(p) = s.last() {
-            if p.ident == "Result" {
-                if let PathArguments::AngleBracketed(a) = &p.arguments {
-                    if let Some(GenericArgument::Type(t)) = a.args.first() {
-                        return Some((SpecialType::Result, t.clone()));
-                    }
-                }
-            } else if p.ident == "Option" {
-                if let PathArguments::AngleBracketed(a) = &p.arguments {
-                    if let Some(GenericArgument::Type(t)) = a.args.first() {
-                        return Some((SpecialType::Option, t.clone()));
-                    }
+/// Replaces `Self` by `self_ty`, to name the type outside of its `impl` block.
+pub(crate) fn replace_self(ty: &syn::Type, self_ty: &syn::Type) -> syn::Type {
+    use syn::visit_mut::{self, VisitMut};
+
+    struct ReplaceSelf<'a>(&'a syn::Type);
+
+    impl VisitMut for ReplaceSelf<'_> {
+        fn visit_type_mut(&mut self, ty: &mut syn::Type) {
+            match ty {
+                syn::Type::Path(path) if path.qself.is_none() && path.path.is_ident("Self") => {
+                    *ty = self.0.clone();
                 }
+                _ => visit_mut::visit_type_mut(self, ty),
+            }
+        }
+    }
+
+    let mut ty = ty.clone();
+    ReplaceSelf(self_ty).visit_type_mut(&mut ty);
+    ty
+}
+
+/// Elides the lifetimes of `ty`, e.g. `Foo<'a>` becomes `Foo<'_>`, to name it outside of
+/// an `impl<'a>` block.
+pub(crate) fn elide_lifetimes(ty: &syn::Type) -> syn::Type {
+    use syn::visit_mut::VisitMut;
+
+    struct Elide;
+
+    impl VisitMut for Elide {
+        fn visit_lifetime_mut(&mut self, lifetime: &mut syn::Lifetime) {
+            if lifetime.ident != "static" {
+                *lifetime = syn::Lifetime::new("'_", lifetime.span());
             }
         }
     }
 
-    None
+    let mut ty = ty.clone();
+    Elide.visit_type_mut(&mut ty);
+    ty
+}
+
+/// An identifier made of `name`, with anything but letters, digits & `_` replaced by `_`,
+/// e.g. `__aoc_gen_day3_part1_Grid_u8___parse` for `__aoc_gen_day3_part1_Grid<u8>::parse`.
+pub(crate) fn to_ident(name: &str, span: pm2::Span) -> syn::Ident {
+    let name: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+
+    syn::Ident::new(&name, span)
 }
 
 pub(crate) fn to_snakecase(dp: &DayPart) -> syn::Ident {
@@ -73,22 +142,3 @@ pub(crate) fn to_camelcase(dp: &DayPart) -> syn::Ident {
 
     syn::Ident::new(&name, pm::Span::call_site().into())
 }
-
-pub(crate) fn to_input(d: Day) -> syn::Ident {
-    syn::Ident::new(&format!("input_day{}", d.0), pm::Span::call_site().into())
-}
-
-pub(crate) fn is_rls() -> bool {
-    use std::env;
-    use std::path;
-
-    let p = match env::var("CARGO") {
-        Ok(p) => p,
-        Err(_) => return false,
-    };
-
-    path::Path::new(&p)
-        .file_stem()
-        .map(|file| file == "rls")
-        .unwrap_or(false)
-}
@@ -0,0 +1,25 @@
+// The setup of the getting started of aoc-runner, with a private `extern crate`
+extern crate aoc_runner;
+extern crate aoc_runner_derive;
+
+use aoc_runner_derive::{aoc, aoc_generator, aoc_lib};
+
+#[aoc_generator(day1)]
+fn parse(input: &str) -> Vec<u32> {
+    input.lines().map(|l| l.parse().unwrap()).collect()
+}
+
+#[aoc(day = 1, part = 1, name = "Sum")]
+fn sum(input: &[u32]) -> u32 {
+    input.iter().sum()
+}
+
+aoc_lib! { year = 2018 }
+
+fn main() {
+    let solution = __aoc_runner::registry()[0];
+    assert_eq!(
+        solution.run("1\n2\n3").unwrap().answer,
+        __aoc_runner::Answer::Integer(6)
+    );
+}
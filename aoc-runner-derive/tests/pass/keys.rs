@@ -0,0 +1,52 @@
+extern crate aoc_runner;
+extern crate aoc_runner_derive;
+
+use aoc_runner_derive::{aoc, aoc_generator, aoc_lib};
+
+#[aoc_generator(day = 2)]
+fn parse(input: &str) -> Vec<u32> {
+    input.lines().map(|l| l.parse().unwrap()).collect()
+}
+
+#[aoc(day = 2, part = 1, name = "Sum")]
+fn sum(input: &[u32]) -> u32 {
+    input.iter().sum()
+}
+
+#[aoc(day = 2, part = 2, params(factor = 3))]
+fn scaled(input: &[u32], params: &aoc_runner::Params) -> u32 {
+    input.iter().sum::<u32>() * params.get::<u32>("factor")
+}
+
+struct Bytes;
+
+#[aoc]
+impl Bytes {
+    #[aoc(day = 3, part = both)]
+    fn count(input: &[u8]) -> (usize, usize) {
+        (input.len(), input.iter().filter(|&&b| b == b'#').count())
+    }
+}
+
+aoc_lib! { year = 2018 }
+
+fn main() {
+    let answers: Vec<_> = __aoc_runner::registry()
+        .iter()
+        .map(|s| {
+            let input = if s.day == 2 { "1\n2" } else { "#.#" };
+            let answer = s.run(input).unwrap().answer.to_string();
+            (s.day, s.part, s.name, answer)
+        })
+        .collect();
+
+    assert_eq!(
+        answers,
+        [
+            (2, 1, Some("Sum"), "3".to_string()),
+            (2, 2, None, "9".to_string()),
+            (3, 1, None, "3".to_string()),
+            (3, 2, None, "2".to_string()),
+        ]
+    );
+}
@@ -0,0 +1,32 @@
+extern crate aoc_runner;
+extern crate aoc_runner_derive;
+
+use aoc_runner_derive::{aoc, aoc_generator, aoc_lib};
+
+// A generator shared by several days, e.g. from a `parse.rs` module
+#[aoc_generator(day3)]
+#[aoc_generator(day6)]
+fn grid(input: &str) -> Vec<Vec<u8>> {
+    input.lines().map(|l| l.bytes().collect()).collect()
+}
+
+#[aoc(day3, part1)]
+fn rows(grid: &[Vec<u8>]) -> usize {
+    grid.len()
+}
+
+#[aoc(day6, part1)]
+fn walls(grid: &[Vec<u8>]) -> usize {
+    grid.iter().flatten().filter(|&&b| b == b'#').count()
+}
+
+aoc_lib! { year = 2018 }
+
+fn main() {
+    let answers: Vec<_> = __aoc_runner::registry()
+        .iter()
+        .map(|s| (s.day, s.run("#.\n##").unwrap().answer.to_string()))
+        .collect();
+
+    assert_eq!(answers, [(3, "2".to_string()), (6, "3".to_string())]);
+}
@@ -0,0 +1,13 @@
+extern crate aoc_runner;
+extern crate aoc_runner_derive;
+
+use aoc_runner_derive::{aoc, aoc_lib};
+
+#[aoc(Day2, part1)]
+fn solve(input: &str) -> usize {
+    input.len()
+}
+
+aoc_lib! { year = 2018 }
+
+fn main() {}
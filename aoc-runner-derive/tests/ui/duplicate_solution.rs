@@ -0,0 +1,18 @@
+extern crate aoc_runner;
+extern crate aoc_runner_derive;
+
+use aoc_runner_derive::{aoc, aoc_lib};
+
+#[aoc(day1, part1)]
+fn solve(input: &str) -> usize {
+    input.len()
+}
+
+#[aoc(day1, part1)]
+fn solve_again(input: &str) -> usize {
+    input.lines().count()
+}
+
+aoc_lib! { year = 2018 }
+
+fn main() {}
@@ -0,0 +1,13 @@
+extern crate aoc_runner;
+extern crate aoc_runner_derive;
+
+use aoc_runner_derive::{aoc, aoc_lib};
+
+#[aoc(day1, part1)]
+fn solve(input: &str) {
+    println!("{}", input.len());
+}
+
+aoc_lib! { year = 2018 }
+
+fn main() {}
@@ -0,0 +1,171 @@
+//! Counting of the allocations of generators & solvers, with `--alloc`.
+//!
+//! The binary must use the counting allocator :
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOC: aoc_runner::alloc::Counting = aoc_runner::alloc::Counting;
+//! ```
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::{self, Display};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A global allocator counting the allocations of each thread, on top of the system one.
+pub struct Counting;
+
+/// The allocations made while running some code.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Allocs {
+    /// Number of allocations (and reallocations).
+    pub count: u64,
+    /// Total size of the allocations.
+    pub bytes: u64,
+    /// Maximum size of the memory allocated at once, and not freed yet.
+    pub peak: u64,
+}
+
+#[derive(Copy, Clone)]
+struct State {
+    count: u64,
+    bytes: u64,
+    // Memory allocated by another thread can be freed by this one
+    live: i64,
+    peak: i64,
+}
+
+static COUNTING: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static STATE: Cell<State> = const {
+        Cell::new(State {
+            count: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+fn record(allocated: usize, freed: usize) {
+    if !COUNTING.load(Ordering::Relaxed) {
+        COUNTING.store(true, Ordering::Relaxed);
+    }
+
+    // The state is gone while the thread is being destroyed
+    let _ = STATE.try_with(|state| {
+        let mut s = state.get();
+        if allocated > 0 {
+            s.count += 1;
+            s.bytes += allocated as u64;
+        }
+        s.live += allocated as i64 - freed as i64;
+        s.peak = s.peak.max(s.live);
+        state.set(s);
+    });
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record(layout.size(), 0);
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record(layout.size(), 0);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        record(0, layout.size());
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record(new_size, layout.size());
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Whether the allocations are counted, i.e. [`Counting`] is the global allocator.
+pub fn is_counting() -> bool {
+    // Makes sure something was allocated
+    drop(Box::new(0u8));
+    COUNTING.load(Ordering::Relaxed)
+}
+
+/// Runs `f`, counting the allocations it makes on the current thread.
+pub fn measure<R, F: FnOnce() -> R>(f: F) -> (R, Allocs) {
+    let reset = State {
+        count: 0,
+        bytes: 0,
+        live: 0,
+        peak: 0,
+    };
+    let outer = STATE.with(|state| state.replace(reset));
+    let result = f();
+    let inner = STATE.with(|state| state.get());
+
+    STATE.with(|state| {
+        state.set(State {
+            count: outer.count + inner.count,
+            bytes: outer.bytes + inner.bytes,
+            live: outer.live + inner.live,
+            peak: outer.peak.max(outer.live + inner.peak),
+        })
+    });
+
+    let allocs = Allocs {
+        count: inner.count,
+        bytes: inner.bytes,
+        peak: inner.peak.max(0) as u64,
+    };
+
+    (result, allocs)
+}
+
+impl Display for Allocs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.count,
+            Bytes(self.bytes),
+            Bytes(self.peak)
+        )
+    }
+}
+
+/// A size in bytes, displayed like `512 B` or `1.5 MiB`, padded to the width asked, if any.
+pub struct Bytes(pub u64);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        if self.0 < 1024 {
+            return f.pad(&format!("{} B", self.0));
+        }
+
+        let mut size = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+        f.pad(&format!("{:.1} {}", size, UNITS[unit]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_bytes() {
+        assert_eq!(Bytes(512).to_string(), "512 B");
+        assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+        assert_eq!(Bytes(3 << 20).to_string(), "3.0 MiB");
+        assert_eq!(format!("{:>10}", Bytes(1536)), "   1.5 KiB");
+    }
+}
@@ -0,0 +1,111 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// A normalised answer, as submitted to the Advent of Code.
+///
+/// Solver outputs are converted through [`IntoAnswer`], so `42u8`, `42i64` and `"42"`
+/// all compare equal.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    Multiline(String),
+}
+
+impl Answer {
+    /// Normalises the textual representation of an answer.
+    ///
+    /// Surrounding whitespace is ignored, integers are parsed, and values spanning several lines
+    /// (like letters drawn on a grid) keep their lines without trailing whitespace.
+    ///
+    /// Only text printed back the same way becomes an [`Answer::Integer`]: codes like `"007"`
+    /// or `"+5"` stay [`Answer::Text`], so their leading zeros and sign are kept.
+    pub fn parse(answer: &str) -> Answer {
+        let trimmed = answer.trim();
+
+        let integer = trimmed.parse::<i128>().ok();
+
+        if let Some(i) = integer.filter(|i| i.to_string() == trimmed) {
+            Answer::Integer(i)
+        } else if trimmed.contains('\n') {
+            let lines: Vec<_> = answer
+                .trim_matches('\n')
+                .lines()
+                .map(str::trim_end)
+                .collect();
+            Answer::Multiline(lines.join("\n"))
+        } else {
+            Answer::Text(trimmed.to_string())
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(i) => Display::fmt(i, f),
+            Answer::Text(s) | Answer::Multiline(s) => Display::fmt(s, f),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = std::convert::Infallible;
+
+    fn from_str(answer: &str) -> Result<Self, Self::Err> {
+        Ok(Answer::parse(answer))
+    }
+}
+
+/// Conversion from the output of a solver to an [`Answer`].
+pub trait IntoAnswer {
+    fn into_answer(self) -> Answer;
+}
+
+impl<T: Display> IntoAnswer for T {
+    fn into_answer(self) -> Answer {
+        Answer::parse(&self.to_string())
+    }
+}
+
+/// Conversion from the output of a solver of both parts, see `#[aoc(dayN, both)]`,
+/// to the [`Answer`]s of part 1 & part 2.
+///
+/// It's implemented for pairs, implement it to return a struct instead.
+pub trait IntoAnswers {
+    fn into_answers(self) -> (Answer, Answer);
+}
+
+impl<A: IntoAnswer, B: IntoAnswer> IntoAnswers for (A, B) {
+    fn into_answers(self) -> (Answer, Answer) {
+        (self.0.into_answer(), self.1.into_answer())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_integers() {
+        assert_eq!(Answer::parse("42"), Answer::Integer(42));
+        assert_eq!(Answer::parse(" 42\n"), Answer::Integer(42));
+        assert_eq!(Answer::parse("-3"), Answer::Integer(-3));
+    }
+
+    #[test]
+    fn parse_keeps_leading_zeros_and_sign() {
+        assert_eq!(Answer::parse("007"), Answer::Text("007".to_string()));
+        assert_eq!(Answer::parse("+5"), Answer::Text("+5".to_string()));
+        assert_eq!(Answer::parse("007").to_string(), "007");
+        assert_eq!(format!("0{}{}", 1, 13).into_answer().to_string(), "0113");
+    }
+
+    #[test]
+    fn parse_multiline() {
+        assert_eq!(
+            Answer::parse("\n#..# \n#### \n"),
+            Answer::Multiline("#..#\n####".to_string())
+        );
+    }
+}
@@ -0,0 +1,1009 @@
+//! Command line of the binaries running the solutions : the `main` generated by `aoc_main!`,
+//! and the runner built by `cargo aoc`.
+//!
+//! ```text
+//! cargo run --release -- -d 5 -p 2 [--name Fnv] [--input path/to/input.txt | --stdin]
+//! ```
+
+use crate::alloc::{self, Allocs};
+use crate::{Answer, ArcStr, Inputs, Params, Solution};
+use std::collections::{BTreeMap, BTreeSet};
+use std::env;
+use std::error::Error;
+use std::fmt::{self, Display, Write as _};
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "usage: [-y YEAR] [-d DAY] [-p PART] [-n NAME] [-i PATH | --stdin] \
+                     [--format text|plain|json] [--repeat N] [--jobs N] [--sequential] \
+                     [--alloc] [--record PATH] [--param KEY=VALUE]... [--list]";
+
+/// The solutions to run, and how.
+#[derive(Clone, Debug)]
+pub struct Args {
+    pub year: Option<u32>,
+    pub day: Option<u8>,
+    pub part: Option<u8>,
+    /// Only runs the solutions with this name (case insensitive).
+    pub name: Option<String>,
+    /// Replaces the downloaded inputs.
+    pub input: Option<Source>,
+    pub params: Params,
+    pub format: Format,
+    /// Runs each generator & solver this many times, reporting the best & mean times.
+    pub repeat: u32,
+    /// Runs independent days (or parts not sharing a generator) on this many threads, or one per
+    /// CPU with `0`.
+    pub jobs: usize,
+    /// Runs a single solution at a time, to measure accurate times, whatever `jobs` says.
+    pub sequential: bool,
+    /// Counts the allocations of each generator & solver, which needs the
+    /// [counting allocator](crate::alloc::Counting).
+    pub alloc: bool,
+    /// Also writes the results in this file, as with [`Format::Json`].
+    pub record: Option<PathBuf>,
+    /// Lists the selected solutions & where they're defined, instead of running them.
+    pub list: bool,
+}
+
+/// Where to read the input from, instead of the downloaded inputs.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+/// How the results are printed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Format {
+    /// The answers, along with the time spent in generators & solvers.
+    Text,
+    /// Only the answers, one per line, e.g. to pipe them in a script.
+    Plain,
+    /// A JSON object per solution and line, with the answer (or the error) and the times in
+    /// nanoseconds.
+    Json,
+}
+
+/// Invalid arguments, or a request for help.
+#[derive(Debug)]
+pub enum ArgsError {
+    Help,
+    Invalid(String),
+}
+
+impl Default for Args {
+    fn default() -> Args {
+        Args {
+            year: None,
+            day: None,
+            part: None,
+            name: None,
+            input: None,
+            params: Params::new(),
+            format: Format::Text,
+            repeat: 1,
+            jobs: 1,
+            sequential: false,
+            alloc: false,
+            record: None,
+            list: false,
+        }
+    }
+}
+
+impl Args {
+    /// Parses the arguments of the process.
+    pub fn parse() -> Result<Args, ArgsError> {
+        Args::parse_from(env::args().skip(1))
+    }
+
+    /// Parses `argv`, without the name of the binary.
+    pub fn parse_from<I: IntoIterator<Item = String>>(argv: I) -> Result<Args, ArgsError> {
+        let mut args = Args::default();
+        let mut argv = argv.into_iter();
+
+        while let Some(arg) = argv.next() {
+            let mut value = || {
+                argv.next()
+                    .ok_or_else(|| ArgsError::Invalid(format!("missing value for {}", arg)))
+            };
+
+            match arg.as_str() {
+                "-h" | "--help" => return Err(ArgsError::Help),
+                "-y" | "--year" => args.year = Some(parse(&value()?)?),
+                "-d" | "--day" => args.day = Some(parse(value()?.trim_start_matches("day"))?),
+                "-p" | "--part" => args.part = Some(parse(value()?.trim_start_matches("part"))?),
+                "-n" | "--name" => args.name = Some(value()?),
+                "-i" | "--input" => {
+                    args.input = Some(match value()?.as_str() {
+                        "-" => Source::Stdin,
+                        path => Source::File(path.into()),
+                    })
+                }
+                "--stdin" => args.input = Some(Source::Stdin),
+                "--format" => args.format = parse(&value()?)?,
+                "--repeat" => args.repeat = parse(&value()?)?,
+                "-j" | "--jobs" => args.jobs = parse(&value()?)?,
+                "--sequential" => args.sequential = true,
+                "--alloc" => args.alloc = true,
+                "--record" => args.record = Some(value()?.into()),
+                "--list" => args.list = true,
+                "--param" => args
+                    .params
+                    .extend(&value()?.parse().map_err(ArgsError::Invalid)?),
+                _ => return Err(ArgsError::Invalid(format!("unexpected argument: {}", arg))),
+            }
+        }
+
+        if args.repeat == 0 {
+            return Err(ArgsError::Invalid(
+                "--repeat must be at least 1".to_string(),
+            ));
+        }
+
+        Ok(args)
+    }
+
+    /// The number of threads running the solutions.
+    pub fn jobs(&self) -> usize {
+        match self.jobs {
+            _ if self.sequential => 1,
+            0 => thread::available_parallelism().map_or(1, |n| n.get()),
+            jobs => jobs,
+        }
+    }
+
+    /// Whether `solution` is selected by the arguments.
+    pub fn matches(&self, solution: &Solution) -> bool {
+        self.year.is_none_or(|y| solution.year == y)
+            && self.day.is_none_or(|d| solution.day == d)
+            && self.part.is_none_or(|p| solution.part == p)
+            && self.name.as_ref().is_none_or(|n| {
+                solution
+                    .name
+                    .is_some_and(|name| name.eq_ignore_ascii_case(n))
+            })
+    }
+}
+
+fn parse<T: FromStr>(value: &str) -> Result<T, ArgsError> {
+    value
+        .parse()
+        .map_err(|_| ArgsError::Invalid(format!("invalid value: {}", value)))
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Format, String> {
+        match format {
+            "text" => Ok(Format::Text),
+            "plain" => Ok(Format::Plain),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format: {}", format)),
+        }
+    }
+}
+
+impl Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArgsError::Help => f.write_str(USAGE),
+            ArgsError::Invalid(e) => write!(f, "{}\n{}", e, USAGE),
+        }
+    }
+}
+
+impl Error for ArgsError {}
+
+/// The `main` of a runner : parses the arguments of the process, runs the selected solutions of
+/// `year` (unless `--year` is given) on `inputs`, and exits with a failure if any of them failed.
+pub fn main(year: u32, inputs: Inputs) -> ! {
+    let mut args = match Args::parse() {
+        Ok(args) => args,
+        Err(ArgsError::Help) => {
+            println!("{}", USAGE);
+            process::exit(0)
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2)
+        }
+    };
+
+    args.year.get_or_insert(year);
+
+    match run(&args, &inputs) {
+        Ok(true) => process::exit(0),
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1)
+        }
+    }
+}
+
+/// Runs the solutions selected by `args`, printing their results as they come, in order.
+///
+/// With several [`jobs`](Args::jobs), each one measures the solutions it runs, but they compete
+/// for the CPU, caches and memory bandwidth : times are only accurate with [`Args::sequential`].
+///
+/// Returns whether every input was found, and every generator & solver succeeded.
+pub fn run(args: &Args, inputs: &Inputs) -> Result<bool, Box<dyn Error>> {
+    let solutions: Vec<_> = crate::registry()
+        .into_iter()
+        .filter(|s| args.matches(s))
+        .collect();
+
+    if solutions.is_empty() {
+        return Err("No matching day & part found".into());
+    }
+
+    if args.list {
+        for solution in &solutions {
+            list(solution, args.format);
+        }
+        return Ok(true);
+    }
+
+    check_params(&solutions, &args.params)?;
+
+    if args.alloc && !alloc::is_counting() {
+        return Err(
+            "--alloc needs the counting allocator, add `#[global_allocator] static ALLOC: \
+                    aoc_runner::alloc::Counting = aoc_runner::alloc::Counting;` to the binary"
+                .into(),
+        );
+    }
+
+    // An explicit input is read once, stdin can't be read twice
+    let given = match &args.input {
+        Some(Source::File(path)) => Some(
+            fs::read_to_string(path)
+                .map_err(|e| format!("failed to read {}: {}", path.display(), e))?,
+        ),
+        Some(Source::Stdin) => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| format!("failed to read stdin: {}", e))?;
+            Some(input)
+        }
+        None => None,
+    };
+
+    // Days whose input is missing are reported as a whole, the others are split into groups of
+    // solutions sharing nothing, run by as many jobs. The input of a day is copied once, out of
+    // the timings of its generators
+    let given = given.as_deref().map(ArcStr::from);
+    let days: Vec<_> = solutions
+        .chunk_by(|a, b| (a.year, a.day) == (b.year, b.day))
+        .map(|day| match &given {
+            Some(input) => (day, Ok(input.clone())),
+            None => (
+                day,
+                inputs
+                    .read(day[0].year, day[0].day)
+                    .map(|input| ArcStr::from(&input))
+                    .map_err(|e| e.to_string()),
+            ),
+        })
+        .collect();
+
+    let tasks: Vec<_> = days
+        .iter()
+        .flat_map(|(day, input)| match input {
+            Ok(input) => independent(day)
+                .into_iter()
+                .map(|group| Task::Run { group, input })
+                .collect(),
+            Err(error) => vec![Task::Input {
+                solution: day[0],
+                error,
+            }],
+        })
+        .collect();
+
+    let mut success = true;
+    let mut year = None;
+    let mut record = String::new();
+
+    execute(
+        &tasks,
+        args.jobs(),
+        |task| task.run(args),
+        |task, events| {
+            if args.format == Format::Text && year != Some(task.year()) {
+                println!("AOC {}", task.year());
+                year = Some(task.year());
+            }
+
+            for event in events {
+                success &= event.is_success();
+                event.print(args.format);
+
+                if args.record.is_some() {
+                    record.push_str(&event.to_json());
+                    record.push('\n');
+                }
+            }
+        },
+    );
+
+    if let Some(path) = &args.record {
+        fs::write(path, record)
+            .map_err(|e| format!("failed to write {}: {}", path.display(), e))?;
+    }
+
+    Ok(success)
+}
+
+/// Fails on the params that none of `solutions` declares, e.g. misspelled, which would
+/// otherwise run on the default values without a warning.
+pub fn check_params(solutions: &[&Solution], params: &Params) -> Result<(), String> {
+    let declared: BTreeSet<_> = solutions
+        .iter()
+        .flat_map(|s| s.params.iter().map(|(key, _)| *key))
+        .collect();
+    let unknown: Vec<_> = params
+        .iter()
+        .map(|(key, _)| key)
+        .filter(|key| !declared.contains(key))
+        .collect();
+
+    if unknown.is_empty() {
+        return Ok(());
+    }
+
+    let names = |keys: Vec<&str>| match keys.is_empty() {
+        true => "none".to_string(),
+        false => keys.join(", "),
+    };
+    Err(format!(
+        "unknown param(s): {} (declared by the selected solutions: {})",
+        names(unknown),
+        names(declared.into_iter().collect())
+    ))
+}
+
+/// Solutions that can run on their own thread.
+enum Task<'a> {
+    /// Solutions sharing the same input, and maybe generators or solvers of both parts.
+    Run {
+        group: Vec<&'a Solution>,
+        input: &'a ArcStr,
+    },
+    /// The input of a day couldn't be read.
+    Input {
+        solution: &'a Solution,
+        error: &'a str,
+    },
+}
+
+impl<'a> Task<'a> {
+    fn year(&self) -> u32 {
+        match self {
+            Task::Run { group, .. } => group[0].year,
+            Task::Input { solution, .. } => solution.year,
+        }
+    }
+
+    fn run(&self, args: &Args) -> Vec<Event<'a>> {
+        match *self {
+            Task::Run { ref group, input } => run_group(args, group, input),
+            Task::Input { solution, error } => vec![Event::Input {
+                solution,
+                error: error.to_string(),
+            }],
+        }
+    }
+}
+
+/// Splits the solutions of a day into groups that can run concurrently, i.e. sharing neither
+/// the output of a generator nor a solver of both parts, in their original order.
+fn independent<'a>(day: &[&'a Solution]) -> Vec<Vec<&'a Solution>> {
+    let linked = |a: &Solution, b: &Solution| a.shares_generator(b) || a.shares_solver(b);
+
+    // The group of each solution, named after its first solution
+    let mut ids: Vec<usize> = (0..day.len()).collect();
+
+    for i in 0..day.len() {
+        for j in 0..i {
+            if linked(day[i], day[j]) && ids[i] != ids[j] {
+                let (merged, into) = (ids[i].max(ids[j]), ids[i].min(ids[j]));
+                ids.iter_mut()
+                    .filter(|id| **id == merged)
+                    .for_each(|id| *id = into);
+            }
+        }
+    }
+
+    (0..day.len())
+        .filter(|&i| ids[i] == i)
+        .map(|group| {
+            day.iter()
+                .zip(&ids)
+                .filter(|&(_, &id)| id == group)
+                .map(|(&solution, _)| solution)
+                .collect()
+        })
+        .collect()
+}
+
+/// Runs the tasks on `jobs` threads, and reports their events in the order of the tasks as soon
+/// as all the previous ones are reported.
+fn execute<'a, T: Sync>(
+    tasks: &[T],
+    jobs: usize,
+    run: impl Fn(&T) -> Vec<Event<'a>> + Sync,
+    mut report: impl FnMut(&T, Vec<Event<'a>>),
+) {
+    if jobs <= 1 {
+        tasks.iter().for_each(|task| report(task, run(task)));
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(tasks.len()) {
+            let (next, run, sender) = (&next, &run, sender.clone());
+
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                match tasks.get(i) {
+                    Some(task) if sender.send((i, run(task))).is_ok() => {}
+                    _ => break,
+                }
+            });
+        }
+
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut reported = 0;
+
+        for (i, events) in receiver {
+            pending.insert(i, events);
+
+            while let Some(events) = pending.remove(&reported) {
+                report(&tasks[reported], events);
+                reported += 1;
+            }
+        }
+    });
+}
+
+fn list(solution: &Solution, format: Format) {
+    match format {
+        Format::Text => println!("{} : {}", solution, solution.location),
+        Format::Plain => println!("{}", solution.location),
+        Format::Json => println!(
+            r#"{{"year":{},"day":{},"part":{},"name":{},"generator":{},"file":{},"line":{}}}"#,
+            solution.year,
+            solution.day,
+            solution.part,
+            solution.name.map_or("null".to_string(), json_string),
+            solution.generator.map_or("null".to_string(), json_string),
+            json_string(solution.location.file),
+            solution.location.line
+        ),
+    }
+}
+
+/// What happened while running a group of solutions, in order.
+enum Event<'a> {
+    Input {
+        solution: &'a Solution,
+        error: String,
+    },
+    Generated {
+        solution: &'a Solution,
+        generator: &'static str,
+        times: Times,
+    },
+    Solved {
+        /// The solutions answered : 2 for a solver of both parts.
+        solutions: Vec<&'a Solution>,
+        answers: Result<Vec<Answer>, Failure>,
+        generator: Duration,
+        runner: Times,
+    },
+}
+
+/// The step at which a solution failed.
+enum Failure {
+    Generating(String),
+    Running(String),
+}
+
+fn run_group<'a>(args: &Args, solutions: &[&'a Solution], input: &ArcStr) -> Vec<Event<'a>> {
+    let mut events = Vec::new();
+
+    // Outputs of the generators of the day (and their time), shared by the solutions using them
+    let mut generated: Vec<(&Solution, _)> = Vec::new();
+
+    for &solution in solutions {
+        // Solvers of both parts run once when both parts are selected, answering for part 2 too
+        let other_part = solutions
+            .iter()
+            .copied()
+            .find(|s| s.part != solution.part && s.shares_solver(solution));
+
+        let part2 = match other_part {
+            Some(_) if solution.part == 2 => continue,
+            part2 => part2,
+        };
+
+        if !generated.iter().any(|(s, _)| solution.shares_generator(s)) {
+            let mut times = Times::default();
+            let output = times.repeat(args.repeat, args.alloc, || solution.generate(input.clone()));
+
+            if let (Ok(_), Some(generator)) = (&output, solution.generator) {
+                events.push(Event::Generated {
+                    solution,
+                    generator,
+                    times: times.clone(),
+                });
+            }
+
+            let output = output.map(|output| (output, times.best()));
+            generated.push((solution, output.map_err(|e| e.to_string())));
+        }
+
+        let solved: Vec<_> = Some(solution).into_iter().chain(part2).collect();
+
+        let (output, generator) = match generated.iter().find(|(s, _)| solution.shares_generator(s))
+        {
+            Some((_, Ok((output, generator)))) => (output, *generator),
+            Some((_, Err(e))) => {
+                events.push(Event::Solved {
+                    solutions: solved,
+                    answers: Err(Failure::Generating(e.clone())),
+                    generator: Duration::ZERO,
+                    runner: Times::default(),
+                });
+                continue;
+            }
+            None => unreachable!(),
+        };
+
+        let mut runner = Times::default();
+        let answers = solution
+            .runner_with(output, &args.params)
+            .and_then(|r| {
+                runner.repeat(args.repeat, args.alloc, || match part2 {
+                    Some(_) => r.try_run_both().map(|(a1, a2)| vec![a1, a2]),
+                    None => r.try_run().map(|answer| vec![answer]),
+                })
+            })
+            .map_err(|e| Failure::Running(e.to_string()));
+
+        events.push(Event::Solved {
+            solutions: solved,
+            answers,
+            generator,
+            runner,
+        });
+    }
+
+    events
+}
+
+/// The durations of the repeated runs of a generator or a solver, and the allocations of the last
+/// one with `--alloc`.
+#[derive(Clone, Debug, Default)]
+struct Times {
+    runs: Vec<Duration>,
+    allocs: Option<Allocs>,
+}
+
+impl Times {
+    /// Runs `f` `count` times, or until it fails, returning its last result.
+    fn repeat<T, E>(
+        &mut self,
+        count: u32,
+        alloc: bool,
+        mut f: impl FnMut() -> Result<T, E>,
+    ) -> Result<T, E> {
+        loop {
+            let start_time = Instant::now();
+            let result = if alloc {
+                let (result, allocs) = alloc::measure(&mut f);
+                self.allocs = Some(allocs);
+                result
+            } else {
+                f()
+            };
+            self.runs.push(start_time.elapsed());
+
+            if result.is_err() || self.runs.len() >= count as usize {
+                return result;
+            }
+        }
+    }
+
+    fn best(&self) -> Duration {
+        self.runs.iter().copied().min().unwrap_or_default()
+    }
+
+    fn mean(&self) -> Duration {
+        self.runs.iter().sum::<Duration>() / self.runs.len().max(1) as u32
+    }
+
+    /// The JSON fields of the allocations, if counted.
+    fn allocs_json(&self) -> Vec<(&'static str, String)> {
+        match self.allocs {
+            Some(allocs) => vec![
+                ("allocs", allocs.count.to_string()),
+                ("alloc_bytes", allocs.bytes.to_string()),
+                ("peak_bytes", allocs.peak.to_string()),
+            ],
+            None => Vec::new(),
+        }
+    }
+}
+
+impl Display for Times {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.runs.len() > 1 {
+            write!(
+                f,
+                "{:?} (best of {}, mean {:?})",
+                self.best(),
+                self.runs.len(),
+                self.mean()
+            )
+        } else {
+            write!(f, "{:?}", self.best())
+        }
+    }
+}
+
+impl Event<'_> {
+    fn is_success(&self) -> bool {
+        match self {
+            Event::Input { .. } => false,
+            Event::Generated { .. } => true,
+            Event::Solved { answers, .. } => answers.is_ok(),
+        }
+    }
+
+    fn print(&self, format: Format) {
+        match format {
+            Format::Text => self.print_text(),
+            Format::Plain => self.print_plain(),
+            Format::Json => println!("{}", self.to_json()),
+        }
+    }
+
+    fn print_text(&self) {
+        match self {
+            Event::Input { solution, error } => {
+                eprintln!(
+                    "Day {} : FAILED while loading the input :\n{}\n",
+                    solution.day, error
+                )
+            }
+            Event::Generated {
+                solution,
+                generator,
+                times,
+            } => {
+                println!("Day {} - Generator {} : {}", solution.day, generator, times);
+                if let Some(allocs) = times.allocs {
+                    println!("\tallocs: {}", allocs);
+                }
+                println!();
+            }
+            Event::Solved {
+                solutions,
+                answers: Ok(answers),
+                runner,
+                ..
+            } => {
+                for (solution, answer) in solutions.iter().zip(answers) {
+                    println!("{} : {}", solution, answer);
+                }
+
+                if solutions.len() > 1 {
+                    println!("\trunner (both parts): {}", runner);
+                } else {
+                    println!("\trunner: {}", runner);
+                }
+                if let Some(allocs) = runner.allocs {
+                    println!("\tallocs: {}", allocs);
+                }
+                println!();
+            }
+            Event::Solved {
+                solutions,
+                answers: Err(failure),
+                ..
+            } => {
+                let (step, error) = match failure {
+                    Failure::Generating(e) => ("generating", e),
+                    Failure::Running(e) => ("running", e),
+                };
+                let names: Vec<_> = solutions.iter().map(ToString::to_string).collect();
+                eprintln!(
+                    "{} : FAILED while {} :\n{}\n",
+                    names.join(" & "),
+                    step,
+                    error
+                );
+            }
+        }
+    }
+
+    fn print_plain(&self) {
+        match self {
+            Event::Solved {
+                answers: Ok(answers),
+                ..
+            } => answers.iter().for_each(|answer| println!("{}", answer)),
+            Event::Generated { .. } => {}
+            _ => self.print_text(),
+        }
+    }
+
+    /// A JSON object per answer (or failure), times in nanoseconds.
+    fn to_json(&self) -> String {
+        let mut json = String::new();
+
+        let mut object = |solution: &Solution, fields: &[(&str, String)]| {
+            if !json.is_empty() {
+                json.push('\n');
+            }
+
+            let name = solution.name.map_or("null".to_string(), json_string);
+            let _ = write!(
+                json,
+                r#"{{"year":{},"day":{},"part":{},"name":{}"#,
+                solution.year, solution.day, solution.part, name
+            );
+            for (key, value) in fields {
+                let _ = write!(json, r#","{}":{}"#, key, value);
+            }
+            json.push('}');
+        };
+
+        match self {
+            Event::Input { solution, error } => object(
+                solution,
+                &[(
+                    "error",
+                    json_string(&format!("loading the input: {}", error)),
+                )],
+            ),
+            Event::Generated {
+                solution,
+                generator,
+                times,
+            } => object(
+                solution,
+                &[
+                    vec![
+                        ("generator", json_string(generator)),
+                        ("best_ns", times.best().as_nanos().to_string()),
+                        ("mean_ns", times.mean().as_nanos().to_string()),
+                        ("runs", times.runs.len().to_string()),
+                    ],
+                    times.allocs_json(),
+                ]
+                .concat(),
+            ),
+            Event::Solved {
+                solutions,
+                answers,
+                generator,
+                runner,
+            } => {
+                for (i, solution) in solutions.iter().enumerate() {
+                    match answers {
+                        Ok(answers) => object(
+                            solution,
+                            &[
+                                vec![
+                                    ("answer", json_string(&answers[i].to_string())),
+                                    ("generator_ns", generator.as_nanos().to_string()),
+                                    ("best_ns", runner.best().as_nanos().to_string()),
+                                    ("mean_ns", runner.mean().as_nanos().to_string()),
+                                    ("runs", runner.runs.len().to_string()),
+                                    ("both", (solutions.len() > 1).to_string()),
+                                ],
+                                runner.allocs_json(),
+                            ]
+                            .concat(),
+                        ),
+                        Err(Failure::Generating(e)) => object(
+                            solution,
+                            &[("error", json_string(&format!("generating: {}", e)))],
+                        ),
+                        Err(Failure::Running(e)) => object(
+                            solution,
+                            &[("error", json_string(&format!("running: {}", e)))],
+                        ),
+                    }
+                }
+            }
+        }
+
+        json
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ArcStr, Generated, Location, Runner};
+    use std::sync::Arc;
+
+    fn generate(input: ArcStr) -> Result<Generated, Box<dyn Error>> {
+        Ok(Arc::new(input))
+    }
+
+    fn runner(_: &Generated, _: Params) -> Result<Box<dyn Runner>, Box<dyn Error>> {
+        Err("not run".into())
+    }
+
+    fn solution(part: u8, params: &'static [(&'static str, &'static str)]) -> Solution {
+        let location = Location {
+            file: file!(),
+            line: line!(),
+            column: column!(),
+        };
+
+        Solution::new(
+            2024, 14, part, None, false, None, None, location, params, generate, runner,
+        )
+    }
+
+    /// A solution of day 14, using the generator of module `generator_id`.
+    fn generated(
+        part: u8,
+        name: Option<&'static str>,
+        both: bool,
+        generator_id: &'static str,
+    ) -> Solution {
+        let location = Location {
+            file: file!(),
+            line: line!(),
+            column: column!(),
+        };
+
+        Solution::new(
+            2024,
+            14,
+            part,
+            name,
+            both,
+            Some("parse"),
+            Some(generator_id),
+            location,
+            &[],
+            generate,
+            runner,
+        )
+    }
+
+    #[test]
+    fn independent_keeps_linked_solutions_together() {
+        let part1 = generated(1, None, false, "day14::parse");
+        let fast = generated(1, Some("Fast"), false, "day14::fast::parse");
+        let part2 = generated(2, None, false, "day14::parse");
+        // Solvers of both parts are linked even with generators of their own
+        let both1 = generated(1, Some("Both"), true, "day14::both::parse_part1");
+        let both2 = generated(2, Some("Both"), true, "day14::both::parse_part2");
+
+        let groups: Vec<Vec<_>> = independent(&[&part1, &fast, &part2, &both1, &both2])
+            .iter()
+            .map(|group| group.iter().map(|s| (s.part, s.name)).collect())
+            .collect();
+
+        assert_eq!(
+            groups,
+            [
+                vec![(1, None), (2, None)],
+                vec![(1, Some("Fast"))],
+                vec![(1, Some("Both")), (2, Some("Both"))],
+            ]
+        );
+    }
+
+    #[test]
+    fn jobs_report_in_the_order_of_sequential_runs() {
+        let sequential = Args::parse_from(["--sequential".to_string()]).unwrap();
+        let parallel = Args::parse_from(["--jobs".to_string(), "4".to_string()]).unwrap();
+        assert_eq!((sequential.jobs(), parallel.jobs()), (1, 4));
+
+        let solution = solution(1, &[]);
+        let tasks: Vec<u64> = (0..8).collect();
+
+        let reported = |jobs| {
+            let mut reported = Vec::new();
+            execute(
+                &tasks,
+                jobs,
+                |&i| {
+                    // The first tasks finish last
+                    thread::sleep(Duration::from_millis(5 * (8 - i)));
+                    vec![Event::Input {
+                        solution: &solution,
+                        error: i.to_string(),
+                    }]
+                },
+                |&i, events| {
+                    reported.extend(events.into_iter().map(|event| match event {
+                        Event::Input { error, .. } => (i, error),
+                        _ => unreachable!(),
+                    }))
+                },
+            );
+            reported
+        };
+
+        let expected: Vec<_> = tasks.iter().map(|&i| (i, i.to_string())).collect();
+        assert_eq!(reported(sequential.jobs()), expected);
+        assert_eq!(reported(parallel.jobs()), expected);
+    }
+
+    #[test]
+    fn check_params_declared_by_any_solution() {
+        let part1 = solution(1, &[("width", "101"), ("height", "103")]);
+        let part2 = solution(2, &[]);
+        let params = Params::new().with("width", 11).with("height", 7);
+
+        assert_eq!(check_params(&[&part1, &part2], &params), Ok(()));
+        assert_eq!(check_params(&[&part2], &Params::new()), Ok(()));
+    }
+
+    #[test]
+    fn check_params_rejects_unknown_keys() {
+        let part1 = solution(1, &[("width", "101"), ("height", "103")]);
+        let part2 = solution(2, &[]);
+
+        assert_eq!(
+            check_params(&[&part1, &part2], &Params::new().with("widht", 11)),
+            Err(
+                "unknown param(s): widht (declared by the selected solutions: height, width)"
+                    .into()
+            )
+        );
+        assert_eq!(
+            check_params(&[&part2], &Params::new().with("width", 11)),
+            Err("unknown param(s): width (declared by the selected solutions: none)".into())
+        );
+    }
+}
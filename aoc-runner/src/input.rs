@@ -0,0 +1,82 @@
+use std::env;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// The inputs downloaded by `cargo aoc input`, stored as `{dir}/{year}/day{day}.txt`
+/// and read at runtime.
+///
+/// The directory can be moved with the `AOC_INPUT_DIR` environment variable.
+#[derive(Clone, Debug)]
+pub struct Inputs {
+    dir: PathBuf,
+}
+
+/// A missing or unreadable input.
+#[derive(Debug)]
+pub struct InputError {
+    pub year: u32,
+    pub day: u8,
+    pub path: PathBuf,
+    pub source: io::Error,
+}
+
+impl Inputs {
+    /// Environment variable overriding the directory of the inputs.
+    pub const DIR_VAR: &'static str = "AOC_INPUT_DIR";
+
+    /// The inputs stored in `dir` (usually `input`), unless `AOC_INPUT_DIR` is set.
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Inputs {
+        let dir = env::var_os(Inputs::DIR_VAR)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| dir.into());
+
+        Inputs { dir }
+    }
+
+    pub fn path(&self, year: u32, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{}.txt", day))
+    }
+
+    pub fn read(&self, year: u32, day: u8) -> Result<String, InputError> {
+        let path = self.path(year, day);
+
+        fs::read_to_string(&path).map_err(|source| InputError {
+            year,
+            day,
+            path,
+            source,
+        })
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.source.kind() == io::ErrorKind::NotFound {
+            write!(
+                f,
+                "missing input {}, download it with `cargo aoc input -d {} -y {}`",
+                self.path.display(),
+                self.day,
+                self.year
+            )
+        } else {
+            write!(
+                f,
+                "failed to read input {}: {}",
+                self.path.display(),
+                self.source
+            )
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
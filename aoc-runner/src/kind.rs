@@ -0,0 +1,378 @@
+//! Conversions of generator & solver outputs, used by the code generated by `aoc-runner-derive`.
+//!
+//! A blanket implementation over `Display` would overlap with implementations for `Option` and
+//! `Result`, so the outputs are dispatched on their type instead: `(&output).aoc_kind()` resolves
+//! to [`OptionKind`] or [`ResultKind`] when they apply, and falls back to [`PlainKind`] through an
+//! extra auto-ref. Type aliases and `impl Display` outputs are handled like any other type.
+//!
+//! Generator outputs are shared as is (see [`Generated`](crate::Generated)), so they are
+//! [`checked`](Fallible::checked) once, then [`Lend`]ed to each solver.
+//! Solvers taking them by value or mutably get a clone, prepared by [`Runner::setup`](crate::Runner::setup)
+//! and recovered with [`downcast_as`], as the type of the generated value can't be named by the macro.
+//!
+//! Solvers are bound to their generator by `aoc_lib!`, which knows all of them once expanded:
+//! it declares a marker type per generator, implementing [`Generator`] and [`Lend`] from the
+//! module of the generator, and aliases the one of each solver (or [`Raw`]).
+//!
+//! Outputs borrowing from the input (`Vec<&str>`, ...) are stored [`Borrowed`] along with it,
+//! and [`check`](Fallible::check)ed in place.
+
+use crate::{Answer, ArcStr, Generated, IntoAnswer, IntoAnswers};
+use std::any::Any;
+use std::borrow::Borrow;
+use std::error::Error;
+use std::fmt::{self, Display};
+
+pub struct Plain;
+
+pub struct Optional;
+
+pub struct Fallible;
+
+pub trait PlainKind {
+    #[inline]
+    fn aoc_kind(&self) -> Plain {
+        Plain
+    }
+}
+
+impl<T: ?Sized> PlainKind for &T {}
+
+pub trait OptionKind {
+    #[inline]
+    fn aoc_kind(&self) -> Optional {
+        Optional
+    }
+}
+
+impl<T> OptionKind for Option<T> {}
+
+pub trait ResultKind {
+    #[inline]
+    fn aoc_kind(&self) -> Fallible {
+        Fallible
+    }
+}
+
+impl<T, E> ResultKind for Result<T, E> {}
+
+impl Plain {
+    #[inline]
+    pub fn checked<T>(self, output: T) -> Result<T, Box<dyn Error>> {
+        Ok(output)
+    }
+
+    #[inline]
+    pub fn check<T>(self, output: &T) -> Result<(), Box<dyn Error>> {
+        let _ = output;
+        Ok(())
+    }
+
+    #[inline]
+    pub fn generated<T>(self, output: &T) -> &T {
+        output
+    }
+
+    #[inline]
+    pub fn answer<T: IntoAnswer>(self, output: T) -> Result<Answer, Box<dyn Error>> {
+        Ok(output.into_answer())
+    }
+
+    #[inline]
+    pub fn answers<T: IntoAnswers>(self, output: T) -> Result<(Answer, Answer), Box<dyn Error>> {
+        Ok(output.into_answers())
+    }
+
+    #[inline]
+    pub fn display<'a, T: Display + 'a>(self, output: &'a T) -> &'a (dyn Display + 'a) {
+        output
+    }
+}
+
+impl Optional {
+    #[inline]
+    pub fn checked<T>(self, output: Option<T>) -> Result<Option<T>, Box<dyn Error>> {
+        if output.is_some() {
+            Ok(output)
+        } else {
+            Err("generator produce no value".into())
+        }
+    }
+
+    #[inline]
+    pub fn check<T>(self, output: &Option<T>) -> Result<(), Box<dyn Error>> {
+        output
+            .as_ref()
+            .map(|_| ())
+            .ok_or_else(|| "generator produce no value".into())
+    }
+
+    #[inline]
+    pub fn generated<T>(self, output: &Option<T>) -> &T {
+        output.as_ref().expect("generator produce no value")
+    }
+
+    #[inline]
+    pub fn answer<T: IntoAnswer>(self, output: Option<T>) -> Result<Answer, Box<dyn Error>> {
+        output
+            .map(IntoAnswer::into_answer)
+            .ok_or_else(|| "runner produce no value".into())
+    }
+
+    #[inline]
+    pub fn answers<T: IntoAnswers>(
+        self,
+        output: Option<T>,
+    ) -> Result<(Answer, Answer), Box<dyn Error>> {
+        output
+            .map(IntoAnswers::into_answers)
+            .ok_or_else(|| "runner produce no value".into())
+    }
+
+    #[inline]
+    pub fn display<'a, T: Display + 'a>(self, output: &'a Option<T>) -> &'a (dyn Display + 'a) {
+        output.as_ref().expect("runner produce no value")
+    }
+}
+
+impl Fallible {
+    #[inline]
+    pub fn checked<T, E>(self, output: Result<T, E>) -> Result<Result<T, E>, Box<dyn Error>>
+    where
+        E: Into<Box<dyn Error>>,
+    {
+        match output {
+            Ok(t) => Ok(Ok(t)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Same as `checked`, for outputs that can't be moved, the error is only kept as a message.
+    #[inline]
+    pub fn check<T, E: Display>(self, output: &Result<T, E>) -> Result<(), Box<dyn Error>> {
+        match output {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e.to_string().into()),
+        }
+    }
+
+    #[inline]
+    pub fn generated<T, E>(self, output: &Result<T, E>) -> &T {
+        match output {
+            Ok(t) => t,
+            Err(_) => panic!("failed to generate input"),
+        }
+    }
+
+    #[inline]
+    pub fn answer<T, E>(self, output: Result<T, E>) -> Result<Answer, Box<dyn Error>>
+    where
+        T: IntoAnswer,
+        E: Into<Box<dyn Error>>,
+    {
+        output.map(IntoAnswer::into_answer).map_err(Into::into)
+    }
+
+    #[inline]
+    pub fn answers<T, E>(self, output: Result<T, E>) -> Result<(Answer, Answer), Box<dyn Error>>
+    where
+        T: IntoAnswers,
+        E: Into<Box<dyn Error>>,
+    {
+        output.map(IntoAnswers::into_answers).map_err(Into::into)
+    }
+
+    #[inline]
+    pub fn display<'a, T: Display + 'a, E>(
+        self,
+        output: &'a Result<T, E>,
+    ) -> &'a (dyn Display + 'a) {
+        match output {
+            Ok(t) => t,
+            Err(_) => panic!("failed to run"),
+        }
+    }
+}
+
+/// Hands the output of a solver of both parts to `black_box`, which only takes a `Display`.
+pub struct Opaque<'a, T>(pub &'a T);
+
+impl<T> Display for Opaque<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("..")
+    }
+}
+
+/// Downcasts `any` to the owned type of `like`.
+#[inline]
+pub fn downcast_as<T>(like: &T, any: Box<dyn Any>) -> T::Owned
+where
+    T: ToOwned + ?Sized,
+    T::Owned: Any,
+{
+    let _ = like;
+    *any.downcast().expect("unexpected runner input")
+}
+
+/// The output type of a generator borrowing from its input, for any lifetime of the input.
+///
+/// `#[aoc_generator]` implements it for generators returning e.g. `Vec<&str>`, with
+/// `type Output<'a> = Vec<&'a str>;`.
+pub trait Family: 'static {
+    type Output<'a>;
+}
+
+/// The output of a generator borrowing from its input, stored along with the input.
+pub struct Borrowed<F: Family> {
+    // Declared first, so it's dropped before the input it borrows from
+    output: F::Output<'static>,
+    _input: ArcStr,
+}
+
+/// Views of an [`ArcStr`] pointing to its heap allocated content, that stays in place when it moves.
+pub trait Content: sealed::Content {
+    fn content(input: &ArcStr) -> &Self;
+}
+
+impl Content for str {
+    fn content(input: &ArcStr) -> &str {
+        input.borrow()
+    }
+}
+
+impl Content for [u8] {
+    fn content(input: &ArcStr) -> &[u8] {
+        input.borrow()
+    }
+}
+
+mod sealed {
+    pub trait Content {}
+
+    impl Content for str {}
+
+    impl Content for [u8] {}
+}
+
+impl<F: Family> Borrowed<F> {
+    pub fn new<B: Content + ?Sized + 'static>(
+        input: ArcStr,
+        generator: for<'a> fn(&'a B) -> F::Output<'a>,
+    ) -> Borrowed<F> {
+        // SAFETY: the content of an `ArcStr` lives on the heap, so it doesn't move along with it,
+        // and it's owned by `self` as long as `output`, which never lends it beyond `&self`.
+        let borrowed: &'static B = unsafe { &*(B::content(&input) as *const B) };
+
+        Borrowed {
+            output: generator(borrowed),
+            _input: input,
+        }
+    }
+
+    /// Lends the output for the lifetime of `&self`.
+    ///
+    /// `f` must work for any lifetime, so it can neither keep the output around, nor
+    /// store something shorter lived in it.
+    pub fn with<R>(&self, f: impl for<'a> FnOnce(&'a F::Output<'a>) -> R) -> R {
+        // SAFETY: `'static` only stands for the lifetime of `self._input`, which outlives `&self`.
+        let output: *const F::Output<'static> = &self.output;
+        let output: &F::Output<'_> = unsafe { &*output.cast() };
+
+        f(output)
+    }
+}
+
+/// The input type of a solver, for any lifetime of the generator output it borrows.
+///
+/// `#[aoc]` implements it for each solver, e.g. `type Input<'a> = [&'a str];` for `&[&str]`.
+pub trait Takes: 'static {
+    type Input<'a>: ?Sized;
+}
+
+/// Generator outputs lent as `X`, either as is or unwrapped from an `Option` or a `Result`,
+/// `K` being inferred from the impl that applies.
+#[diagnostic::on_unimplemented(
+    message = "the generator output `{Self}` can't be lent as `{X}`",
+    label = "this solution doesn't take the output of its generator",
+    note = "the output must implement `Borrow<{X}>`, or be an `Option` or a `Result` of such a type"
+)]
+pub trait LendsAs<X: ?Sized, K> {
+    fn lent(&self) -> &X;
+}
+
+impl<T: Borrow<X>, X: ?Sized> LendsAs<X, Plain> for T {
+    #[inline]
+    fn lent(&self) -> &X {
+        self.borrow()
+    }
+}
+
+impl<T: Borrow<X>, X: ?Sized> LendsAs<X, Optional> for Option<T> {
+    #[inline]
+    fn lent(&self) -> &X {
+        Optional.generated(self).borrow()
+    }
+}
+
+impl<T: Borrow<X>, E, X: ?Sized> LendsAs<X, Fallible> for Result<T, E> {
+    #[inline]
+    fn lent(&self) -> &X {
+        Fallible.generated(self).borrow()
+    }
+}
+
+/// The generator of one or more solutions, implemented by `#[aoc_generator]` on the marker
+/// type `aoc_lib!` declares for it.
+pub trait Generator {
+    const NAME: Option<&'static str>;
+
+    /// The path of the module emitted along with the generator, unique to each of them,
+    /// unlike their names (e.g. `parse` of two types).
+    const ID: Option<&'static str>;
+
+    fn generate(input: ArcStr) -> Result<Generated, Box<dyn Error>>;
+
+    /// Whether `generated` is an output of this generator.
+    fn accepts(generated: &Generated) -> bool;
+}
+
+/// Lends the output of a generator to the solvers taking `A`.
+pub trait Lend<A: Takes, K>: Generator {
+    fn lend<R>(generated: &Generated, f: impl for<'a> FnOnce(&'a A::Input<'a>) -> R) -> R;
+}
+
+/// Lends the output of the generator `G` to a solver taking `A`, inferring how it's unwrapped.
+#[inline]
+pub fn lend<G, A, K, R>(generated: &Generated, f: impl for<'a> FnOnce(&'a A::Input<'a>) -> R) -> R
+where
+    G: Lend<A, K>,
+    A: Takes,
+{
+    G::lend(generated, f)
+}
+
+/// The generator of solutions without one, lending the input as is.
+pub struct Raw;
+
+impl Generator for Raw {
+    const NAME: Option<&'static str> = None;
+    const ID: Option<&'static str> = None;
+
+    fn generate(input: ArcStr) -> Result<Generated, Box<dyn Error>> {
+        Ok(std::sync::Arc::new(input))
+    }
+
+    fn accepts(generated: &Generated) -> bool {
+        generated.is::<ArcStr>()
+    }
+}
+
+impl<A: Takes, K> Lend<A, K> for Raw
+where
+    for<'a> ArcStr: LendsAs<A::Input<'a>, K>,
+{
+    #[inline]
+    fn lend<R>(generated: &Generated, f: impl for<'a> FnOnce(&'a A::Input<'a>) -> R) -> R {
+        f(generated.downcast_ref::<ArcStr>().unwrap().lent())
+    }
+}
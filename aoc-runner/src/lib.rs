@@ -1,3 +1,23 @@
+#[doc(hidden)]
+pub extern crate inventory;
+
+pub mod alloc;
+mod answer;
+pub mod cli;
+mod input;
+#[doc(hidden)]
+pub mod kind;
+mod params;
+mod registry;
+
+pub use crate::answer::{Answer, IntoAnswer, IntoAnswers};
+pub use crate::input::{InputError, Inputs};
+pub use crate::params::Params;
+pub use crate::registry::{
+    registry, Generated, GeneratorFn, Location, Outcome, RunnerFactory, Solution,
+};
+
+use std::any::Any;
 use std::borrow::Borrow;
 use std::error::Error;
 use std::fmt::Display;
@@ -37,22 +57,29 @@ impl Borrow<Arc<str>> for ArcStr {
 }
 
 pub trait Runner {
-    fn gen(input: ArcStr) -> Self
-    where
-        Self: Sized;
-
-    fn run(&self) -> Box<dyn Display>;
+    fn run(&self) -> Answer;
 
     fn bench(&self, black_box: fn(&dyn Display));
 
-    fn try_gen(input: ArcStr) -> Result<Self, Box<dyn Error>>
-    where
-        Self: Sized,
-    {
-        Ok(Self::gen(input))
+    fn try_run(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(self.run())
+    }
+
+    /// Runs a solution of both parts (see [`Solution::both`]) once, returning both answers.
+    fn try_run_both(&self) -> Result<(Answer, Answer), Box<dyn Error>> {
+        Err("this solution doesn't solve both parts at once".into())
     }
 
-    fn try_run(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(self.run())
+    /// Prepares the input of a single call to [`Runner::bench_batched`], outside of the measure.
+    ///
+    /// Solvers taking the generator output by value or by `&mut` get their own clone.
+    fn setup(&self) -> Box<dyn Any> {
+        Box::new(())
+    }
+
+    /// Same as [`Runner::bench`], with an input prepared by [`Runner::setup`].
+    fn bench_batched(&self, input: Box<dyn Any>, black_box: fn(&dyn Display)) {
+        let _ = input;
+        self.bench(black_box)
     }
 }
@@ -0,0 +1,118 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// Named constants of a solution, that often differ between the examples and the real input.
+///
+/// Defaults are declared on the solution, `#[aoc(day14, part1, params(width = 101, height = 103))]`,
+/// which then receives them as a second argument :
+/// ```ignore
+/// fn part1(robots: &[Robot], params: &Params) -> usize {
+///     let width: i32 = params.get("width");
+///     ...
+/// }
+/// ```
+/// They can be overridden without code changes, through [`Solution::run_with`](crate::Solution::run_with)
+/// or `cargo aoc --param width=11 --param height=7`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    pub fn new() -> Params {
+        Params::default()
+    }
+
+    /// Sets `key` to `value`, overriding the previous value.
+    pub fn with<V: ToString>(mut self, key: &str, value: V) -> Params {
+        self.set(key, value);
+        self
+    }
+
+    pub fn set<V: ToString>(&mut self, key: &str, value: V) {
+        self.0.insert(key.to_string(), value.to_string());
+    }
+
+    /// Overrides the values of `self` with the ones of `other`.
+    pub fn extend(&mut self, other: &Params) {
+        self.0
+            .extend(other.0.iter().map(|(k, v)| (k.clone(), v.clone())));
+    }
+
+    /// Parses the value of `key`.
+    ///
+    /// # Panics
+    /// If `key` is missing, or its value can't be parsed.
+    pub fn get<T: FromStr>(&self, key: &str) -> T
+    where
+        T::Err: Display,
+    {
+        self.try_get(key)
+            .unwrap_or_else(|e| panic!("failed to get param: {}", e))
+    }
+
+    /// Parses the value of `key`.
+    pub fn try_get<T: FromStr>(&self, key: &str) -> Result<T, Box<dyn Error>>
+    where
+        T::Err: Display,
+    {
+        let value = self
+            .0
+            .get(key)
+            .ok_or_else(|| format!("missing param {}", key))?;
+
+        value
+            .parse()
+            .map_err(|e| format!("invalid value for {}: {:?} ({})", key, value, e).into())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
+
+impl<'a> From<&'a [(&'a str, &'a str)]> for Params {
+    fn from(params: &'a [(&'a str, &'a str)]) -> Params {
+        Params(
+            params
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        )
+    }
+}
+
+/// Parses a comma separated list of `key=value`, e.g. `width=11,height=7`.
+impl FromStr for Params {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Params, String> {
+        let mut params = Params::new();
+
+        for param in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let mut kv = param.splitn(2, '=');
+            match (kv.next(), kv.next()) {
+                (Some(k), Some(v)) if !k.trim().is_empty() => params.set(k.trim(), v.trim()),
+                _ => return Err(format!("invalid param {:?}, expected key=value", param)),
+            }
+        }
+
+        Ok(params)
+    }
+}
+
+impl Display for Params {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, (k, v)) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}={}", k, v)?;
+        }
+        Ok(())
+    }
+}
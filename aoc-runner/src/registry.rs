@@ -0,0 +1,245 @@
+use crate::{Answer, ArcStr, Params, Runner};
+use inventory;
+use std::any::Any;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// The output of a generator (or the raw input, for solutions without generator),
+/// shared by every solution of the day using the same generator.
+pub type Generated = Arc<dyn Any>;
+
+/// Runs the generator of a solution on its input.
+pub type GeneratorFn = fn(ArcStr) -> Result<Generated, Box<dyn Error>>;
+
+/// Builds the runner of a solution from the output of its generator and its params.
+pub type RunnerFactory = fn(&Generated, Params) -> Result<Box<dyn Runner>, Box<dyn Error>>;
+
+/// Where a solution is defined, as reported by `file!()`, `line!()` and `column!()`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Location {
+    pub file: &'static str,
+    pub line: u32,
+    pub column: u32,
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+/// A solution flagged with `#[aoc]`, registered when the crate is linked.
+pub struct Solution {
+    pub year: u32,
+    pub day: u8,
+    pub part: u8,
+    pub name: Option<&'static str>,
+    /// Whether the solver solves both parts at once, with `#[aoc(dayN, both)]`.
+    ///
+    /// It's then registered for each part, and [`Runner::try_run_both`] gets both answers.
+    pub both: bool,
+    pub generator: Option<&'static str>,
+    /// Identifies the generator, as several can have the same name.
+    generator_id: Option<&'static str>,
+    pub location: Location,
+    /// Default params, as declared with `params(key = value, ...)`.
+    pub params: &'static [(&'static str, &'static str)],
+    generate: GeneratorFn,
+    runner: RunnerFactory,
+}
+
+/// The answer of a solution, along with the time spent in the generator and in the solver.
+pub struct Outcome {
+    pub answer: Answer,
+    pub generator: Duration,
+    pub runner: Duration,
+}
+
+impl Solution {
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        year: u32,
+        day: u8,
+        part: u8,
+        name: Option<&'static str>,
+        both: bool,
+        generator: Option<&'static str>,
+        generator_id: Option<&'static str>,
+        location: Location,
+        params: &'static [(&'static str, &'static str)],
+        generate: GeneratorFn,
+        runner: RunnerFactory,
+    ) -> Solution {
+        Solution {
+            year,
+            day,
+            part,
+            name,
+            both,
+            generator,
+            generator_id,
+            location,
+            params,
+            generate,
+            runner,
+        }
+    }
+
+    /// Runs the generator on `input`.
+    ///
+    /// The output can be given to [`Solution::runner`] of every solution that
+    /// [shares this generator](Solution::shares_generator).
+    pub fn generate(&self, input: ArcStr) -> Result<Generated, Box<dyn Error>> {
+        (self.generate)(input)
+    }
+
+    /// Builds the runner from the output of the generator, without running the solver.
+    pub fn runner(&self, generated: &Generated) -> Result<Box<dyn Runner>, Box<dyn Error>> {
+        (self.runner)(generated, self.params())
+    }
+
+    /// Same as [`Solution::runner`], with some of the default params overridden.
+    pub fn runner_with(
+        &self,
+        generated: &Generated,
+        params: &Params,
+    ) -> Result<Box<dyn Runner>, Box<dyn Error>> {
+        let mut merged = self.params();
+        merged.extend(params);
+        (self.runner)(generated, merged)
+    }
+
+    /// The default params of the solution.
+    pub fn params(&self) -> Params {
+        Params::from(self.params)
+    }
+
+    /// Builds the runner for `input`, without running the solver.
+    pub fn gen(&self, input: &str) -> Result<Box<dyn Runner>, Box<dyn Error>> {
+        self.runner(&self.generate(ArcStr::from(input))?)
+    }
+
+    /// Whether both solutions use the same generator output for a given input.
+    pub fn shares_generator(&self, other: &Solution) -> bool {
+        (self.year, self.day, self.generator_id) == (other.year, other.day, other.generator_id)
+    }
+
+    /// Whether both solutions are the parts of the same solver of both parts.
+    pub fn shares_solver(&self, other: &Solution) -> bool {
+        self.both
+            && other.both
+            && (self.year, self.day, self.name) == (other.year, other.day, other.name)
+    }
+
+    /// Runs the generator then the solver on `input`, timing both.
+    pub fn run(&self, input: &str) -> Result<Outcome, Box<dyn Error>> {
+        self.run_with(input, &Params::new())
+    }
+
+    /// Same as [`Solution::run`], with some of the default params overridden,
+    /// e.g. to run on the examples of the puzzle.
+    pub fn run_with(&self, input: &str, params: &Params) -> Result<Outcome, Box<dyn Error>> {
+        let input = ArcStr::from(input);
+        let start_time = Instant::now();
+        let runner = self.runner_with(&self.generate(input)?, params)?;
+        let inter_time = Instant::now();
+        let answer = runner.try_run()?;
+        let final_time = Instant::now();
+
+        Ok(Outcome {
+            answer,
+            generator: inter_time - start_time,
+            runner: final_time - inter_time,
+        })
+    }
+}
+
+impl fmt::Debug for Solution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Solution")
+            .field("year", &self.year)
+            .field("day", &self.day)
+            .field("part", &self.part)
+            .field("name", &self.name)
+            .field("both", &self.both)
+            .field("generator", &self.generator)
+            .field("location", &self.location)
+            .field("params", &self.params)
+            .finish()
+    }
+}
+
+impl Display for Solution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Day {} - Part {}", self.day, self.part)?;
+        if let Some(name) = self.name {
+            write!(f, " - {}", name)?;
+        }
+        Ok(())
+    }
+}
+
+inventory::collect!(Solution);
+
+/// Lists every solution linked into the current binary, sorted by year, day, part and name.
+///
+/// This doesn't need `cargo aoc`: tests, custom drivers or notebooks can iterate over it
+/// and call [`Solution::run`] directly.
+pub fn registry() -> Vec<&'static Solution> {
+    let mut solutions: Vec<_> = inventory::iter::<Solution>.into_iter().collect();
+    solutions.sort_by(|a, b| (a.year, a.day, a.part, a.name).cmp(&(b.year, b.day, b.part, b.name)));
+    solutions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generate(input: ArcStr) -> Result<Generated, Box<dyn Error>> {
+        Ok(Arc::new(input))
+    }
+
+    fn runner(_: &Generated, _: Params) -> Result<Box<dyn Runner>, Box<dyn Error>> {
+        Err("not run".into())
+    }
+
+    fn solution(part: u8, generator_id: Option<&'static str>) -> Solution {
+        let location = Location {
+            file: file!(),
+            line: line!(),
+            column: column!(),
+        };
+        let generator = generator_id.map(|_| "parse");
+
+        Solution::new(
+            2018,
+            8,
+            part,
+            None,
+            false,
+            generator,
+            generator_id,
+            location,
+            &[],
+            generate,
+            runner,
+        )
+    }
+
+    #[test]
+    fn shares_generator_by_id() {
+        let part1 = solution(1, Some("day8::__aoc_gen_day8_part1_parse"));
+        let part2 = solution(2, Some("day8::__aoc_gen_day8_part1_parse"));
+        assert!(part1.shares_generator(&part2));
+
+        // Same name, in another module or `impl` block
+        let other = solution(2, Some("day8::nested::__aoc_gen_day8_part1_parse"));
+        assert!(!part1.shares_generator(&other));
+
+        assert!(solution(1, None).shares_generator(&solution(2, None)));
+        assert!(!solution(1, None).shares_generator(&part2));
+    }
+}
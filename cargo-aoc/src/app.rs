@@ -1,5 +1,13 @@
 use crate::{
-    credentials::CredentialsManager, date, project::ProjectManager, Bench, Credentials, Input,
+    baseline::Baseline,
+    bench::{add_allocations, print_summary, print_year, Backend, Benchmark, Budgets, Kind},
+    credentials::CredentialsManager,
+    date,
+    history::{self, Entry},
+    project::ProjectManager,
+    readme::{self, Row, Titles},
+    status,
+    Bench, Credentials, History, Input, Profile, Readme,
 };
 use aoc_runner_internal::{Day, Part};
 use date::AOCDate;
@@ -7,9 +15,12 @@ use reqwest::{
     header::{HeaderMap, COOKIE, USER_AGENT},
     StatusCode,
 };
+use std::collections::BTreeMap;
+use std::env;
 use std::io::Write;
 use std::path::Path;
-use std::process;
+use serde_json::Value;
+use std::process::{self, Stdio};
 use std::{error, sync::Arc};
 use std::{
     error::Error,
@@ -20,6 +31,12 @@ use crate::Cli;
 
 const CARGO_AOC_USER_AGENT: &str = "github.com/gobanos/cargo-aoc by gregory.obanos@gmail.com";
 
+/// Installs the allocator counting the allocations, for `--alloc`.
+const ALLOC: &str = "\n#[global_allocator]\nstatic ALLOC: {CRATE_SLUG}::__aoc_runner::alloc::Counting = {CRATE_SLUG}::__aoc_runner::alloc::Counting;\n";
+
+/// The bench counting the allocations of the others, for `cargo aoc bench --alloc`.
+const ALLOC_BENCH: &str = "\n[[bench]]\nname = \"aoc_allocations\"\nharness = false\n";
+
 pub fn execute_credentials(args: &Credentials) {
     let mut creds_manager = CredentialsManager::new();
 
@@ -260,6 +277,76 @@ pub fn execute_default(args: &Cli) -> Result<(), Box<dyn error::Error>> {
         day_parts = pm.build_project()?;
     }
 
+    if !day_parts
+        .iter()
+        .any(|dp| dp.day == day && part.is_none_or(|p| dp.part == p))
+    {
+        return Err("No matching day & part found".into());
+    }
+
+    if args.input.is_none() {
+        download_input(date)?;
+    }
+
+    write_runner(&pm, args.profile, args.alloc);
+
+    let mut runner_args = vec![
+        "run".to_string(),
+        "--release".to_string(),
+        "--manifest-path".to_string(),
+        "target/aoc/aoc-autobuild/Cargo.toml".to_string(),
+        "--".to_string(),
+        "--day".to_string(),
+        day.0.to_string(),
+    ];
+    if let Some(p) = part {
+        runner_args.extend(["--part".to_string(), p.0.to_string()]);
+    }
+    if let Some(input) = &args.input {
+        runner_args.extend(["--input".to_string(), input.clone()]);
+    }
+    for param in &args.params {
+        runner_args.extend(["--param".to_string(), param.clone()]);
+    }
+    if let Some(jobs) = args.jobs {
+        runner_args.extend(["--jobs".to_string(), jobs.to_string()]);
+    }
+    if args.sequential {
+        runner_args.push("--sequential".to_string());
+    }
+    if args.alloc {
+        runner_args.push("--alloc".to_string());
+    }
+
+    // Results of the run, for the history
+    let record = "target/aoc/aoc-autobuild/results.jsonl";
+    runner_args.extend(["--record".to_string(), record.to_string()]);
+    if Path::new(record).exists() {
+        fs::remove_file(record)?;
+    }
+
+    let status = process::Command::new("cargo")
+        .args(&runner_args)
+        .spawn()
+        .expect("Failed to run cargo")
+        .wait()
+        .expect("Failed to wait for cargo");
+
+    if Path::new(record).exists() {
+        Entry::from_run(Path::new(record))?.append()?;
+    }
+
+    if !status.success() {
+        process::exit(status.code().unwrap_or(-1));
+    }
+    Ok(())
+}
+
+/// Writes the crate of the runner in `target/aoc/aoc-autobuild`.
+///
+/// The runner selects the day & part at runtime, so it is only rebuilt when the
+/// solutions (or the templates) change.
+fn write_runner(pm: &ProjectManager, profile: bool, alloc: bool) {
     let cargo_content = include_str!(concat!(
         env!("CARGO_MANIFEST_DIR"),
         "/template/Cargo-run.toml.tpl"
@@ -267,78 +354,34 @@ pub fn execute_default(args: &Cli) -> Result<(), Box<dyn error::Error>> {
     .replace("{CRATE_NAME}", &pm.name)
     .replace(
         "{PROFILE}",
-        if args.profile {
+        if profile {
             "[profile.release]\ndebug = true"
         } else {
             ""
         },
     );
 
-    let template = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/template/src/runner.rs.tpl"
-    ));
-
-    let mut body = String::new();
-    for dp in day_parts.iter().filter(|dp| dp.day == day).filter(|dp| {
-        if let Some(p) = part {
-            dp.part == p
-        } else {
-            true
-        }
-    }) {
-        let (name, display) = if let Some(n) = &dp.name {
-            (
-                format!("day{}_part{}_{}", dp.day.0, dp.part.0, n.to_lowercase()),
-                format!("Day {} - Part {} - {}", dp.day.0, dp.part.0, n),
-            )
-        } else {
-            (
-                format!("day{}_part{}", dp.day.0, dp.part.0),
-                format!("Day {} - Part {}", dp.day.0, dp.part.0),
-            )
-        };
-
-        body += &template
-            .replace("{DAY}", &day.0.to_string())
-            .replace("{RUNNER_NAME}", &name)
-            .replace("{RUNNER_DISPLAY}", &display);
-    }
-
-    if body.is_empty() {
-        return Err("No matching day & part found".into());
-    }
-
-    download_input(date)?;
-
     let main_content = include_str!(concat!(
         env!("CARGO_MANIFEST_DIR"),
         "/template/src/main.rs.tpl"
     ))
-    .replace("{CRATE_SLUG}", &pm.slug)
-    .replace("{YEAR}", &day_parts.year.to_string())
-    .replace("{INPUT}", &template_input(day, year, args.input.as_deref()))
-    .replace("{BODY}", &body);
+    .replace("{ALLOC}", if alloc { ALLOC } else { "" })
+    .replace("{CRATE_SLUG}", &pm.slug);
 
     fs::create_dir_all("target/aoc/aoc-autobuild/src")
         .expect("failed to create autobuild directory");
-    fs::write("target/aoc/aoc-autobuild/Cargo.toml", cargo_content)
+    write_if_changed("target/aoc/aoc-autobuild/Cargo.toml", &cargo_content)
         .expect("failed to write Cargo.toml");
-    fs::write("target/aoc/aoc-autobuild/src/main.rs", main_content)
+    write_if_changed("target/aoc/aoc-autobuild/src/main.rs", &main_content)
         .expect("failed to write src/main.rs");
+}
 
-    let status = process::Command::new("cargo")
-        .args(["run", "--release"])
-        .current_dir("target/aoc/aoc-autobuild")
-        .spawn()
-        .expect("Failed to run cargo")
-        .wait()
-        .expect("Failed to wait for cargo");
-
-    if !status.success() {
-        process::exit(status.code().unwrap_or(-1));
+/// Only touches `path` if its content differs, so cargo doesn't rebuild an unchanged crate.
+fn write_if_changed(path: &str, content: &str) -> std::io::Result<()> {
+    if fs::read_to_string(path).is_ok_and(|current| current == content) {
+        return Ok(());
     }
-    Ok(())
+    fs::write(path, content)
 }
 
 pub fn execute_bench(args: &Bench) -> Result<(), Box<dyn error::Error>> {
@@ -352,179 +395,105 @@ pub fn execute_bench(args: &Bench) -> Result<(), Box<dyn error::Error>> {
     let day = day.unwrap_or_else(|| day_parts.last().expect("No implementation found").day);
     let year = day_parts.year;
 
-    let cargo_content = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/template/Cargo-bench.toml.tpl"
-    ))
-    .replace("{CRATE_NAME}", &pm.name)
-    .replace(
-        "{PROFILE}",
-        if args.profile {
-            "[profile.release]\ndebug = true"
-        } else {
-            ""
-        },
-    );
+    let backend = args.backend.or(pm.bench_backend).unwrap_or_default();
+    let templates = backend.templates();
 
-    let bench_tpl = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/template/benches/aoc_benchmark.rs.tpl"
-    ));
+    if args.open && backend != Backend::Criterion {
+        return Err(format!("only Criterion generates a report to open, not {}", backend).into());
+    }
 
-    let part_tpl = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/template/benches/part.rs.tpl"
-    ));
+    // Fails before measuring anything if the baseline is missing
+    let baseline = args
+        .baseline
+        .as_deref()
+        .map(|name| Baseline::load(name).map(|baseline| (name, baseline)))
+        .transpose()?;
 
-    let gen_tpl = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/template/benches/gen.rs.tpl"
-    ));
+    let cargo_content = templates
+        .cargo
+        .replace("{CRATE_NAME}", &pm.name)
+        .replace(
+            "{PROFILE}",
+            if args.profile {
+                "[profile.release]\ndebug = true"
+            } else {
+                ""
+            },
+        )
+        .replace("{ALLOC_BENCH}", if args.alloc { ALLOC_BENCH } else { "" });
 
-    let impl_tpl = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/template/benches/impl.rs.tpl"
-    ));
+    let days: Vec<Day> = if args.all {
+        let mut days: Vec<_> = day_parts.iter().map(|dp| dp.day).collect();
+        days.dedup();
+        days
+    } else {
+        vec![day]
+    };
 
-    let gen_impl_tpl = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/template/benches/gen_impl.rs.tpl"
-    ));
+    let matching_parts: Vec<_> = day_parts
+        .iter()
+        .filter(|dp| days.contains(&dp.day) && part.is_none_or(|p| dp.part == p))
+        .collect();
 
-    let matching_parts = day_parts.iter().filter(|dp| dp.day == day).filter(|dp| {
-        if let Some(p) = part {
-            dp.part == p
-        } else {
-            true
-        }
-    });
+    if matching_parts.is_empty() {
+        return Err("No matching day & part found".into());
+    }
 
-    let mut parts: Vec<_> = matching_parts.clone().map(|dp| dp.part).collect();
-    parts.sort();
-    parts.dedup();
-
-    let body: String = parts
-        .into_iter()
-        .map(|p| {
-            let part_name = format!("day{}_part{}", day.0, p.0);
-            part_tpl
-                .replace("{PART_NAME}", &part_name)
-                .replace("{DAY}", &day.0.to_string())
-                .replace("{PART}", &p.0.to_string())
-                .replace(
-                    "{IMPLS}",
-                    &matching_parts
-                        .clone()
-                        .filter(|dp| dp.part == p)
-                        .map(|dp| {
-                            impl_tpl
-                                .replace(
-                                    "{RUNNER_NAME}",
-                                    &if let Some(n) = &dp.name {
-                                        format!(
-                                            "day{}_part{}_{}",
-                                            dp.day.0,
-                                            dp.part.0,
-                                            n.to_lowercase()
-                                        )
-                                    } else {
-                                        format!("day{}_part{}", dp.day.0, dp.part.0)
-                                    },
-                                )
-                                .replace("{DAY}", &dp.day.0.to_string())
-                                .replace(
-                                    "{NAME}",
-                                    if let Some(n) = &dp.name {
-                                        n
-                                    } else {
-                                        "(default)"
-                                    },
-                                )
-                                .replace("{PART_NAME}", &part_name)
-                        })
-                        .collect::<String>(),
-                )
+    let benchmarks_of = |kind| {
+        matching_parts.iter().map(move |&dp| Benchmark {
+            day_part: dp.clone(),
+            kind,
         })
+    };
+
+    let solvers: Vec<_> = benchmarks_of(Kind::Solver).collect();
+    let generators: Vec<_> = benchmarks_of(Kind::Generator)
+        .filter(|_| args.generator)
+        .collect();
+    // The year report times the default solution of each part (or its first named one)
+    let end_to_end: Vec<_> = benchmarks_of(Kind::EndToEnd)
+        .filter(|_| args.all)
+        .collect::<Vec<_>>()
+        .chunk_by(|a, b| (a.day_part.day, a.day_part.part) == (b.day_part.day, b.day_part.part))
+        .map(|part| part[0].clone())
         .collect();
 
-    if body.is_empty() {
-        return Err("No matching day & part found".into());
+    let dates: Vec<_> = days
+        .iter()
+        .map(|day| AOCDate {
+            day: u32::from(day.0),
+            year: year as i32,
+        })
+        .collect();
+
+    if args.input.is_none() {
+        for date in &dates {
+            download_input(*date)?;
+        }
     }
 
-    let gens = if args.generator {
-        let mut parts: Vec<_> = matching_parts.clone().map(|dp| dp.part).collect();
-        parts.sort();
-        parts.dedup();
-
-        parts
-            .into_iter()
-            .map(|p| {
-                let gen_name = format!("day{}", day.0);
-                gen_tpl
-                    .replace("{GEN_NAME}", &gen_name)
-                    .replace("{DAY}", &day.0.to_string())
-                    .replace("{PART}", &p.0.to_string())
-                    .replace(
-                        "{IMPLS}",
-                        &matching_parts
-                            .clone()
-                            .filter(|dp| dp.part == p)
-                            .map(|dp| {
-                                gen_impl_tpl
-                                    .replace(
-                                        "{RUNNER_NAME}",
-                                        &if let Some(n) = &dp.name {
-                                            format!(
-                                                "day{}_part{}_{}",
-                                                dp.day.0,
-                                                dp.part.0,
-                                                n.to_lowercase()
-                                            )
-                                        } else {
-                                            format!("day{}_part{}", dp.day.0, dp.part.0)
-                                        },
-                                    )
-                                    .replace("{DAY}", &dp.day.0.to_string())
-                                    .replace(
-                                        "{NAME}",
-                                        if let Some(n) = &dp.name {
-                                            n
-                                        } else {
-                                            "(default)"
-                                        },
-                                    )
-                                    .replace("{GEN_NAME}", &gen_name)
-                            })
-                            .collect::<String>(),
-                    )
-            })
-            .collect()
-    } else {
-        String::new()
+    let input = |day: Day| {
+        let date = dates.iter().find(|date| date.day == u32::from(day.0)).unwrap();
+        template_input(date, args.input.as_deref())
     };
 
-    let date = AOCDate {
-        day: u32::from(day.0),
-        year: year as i32,
+    let parts = templates.render(&solvers, input) + &templates.render(&end_to_end, input);
+    let gens = templates.render(&generators, input);
+    let inputs: String = days.iter().map(|&day| input(day)).collect();
+
+    let benchmark_fns = if args.generator {
+        "aoc_benchmark, input_benchmark"
+    } else {
+        "aoc_benchmark"
     };
-    download_input(date)?;
 
-    let main_content = bench_tpl
+    let main_content = templates
+        .bench
         .replace("{CRATE_SLUG}", &pm.slug)
-        .replace("{PARTS}", &body)
+        .replace("{PARTS}", &parts)
         .replace("{GENS}", &gens)
-        .replace(
-            "{BENCHMARKS}",
-            if args.generator {
-                "aoc_benchmark, input_benchmark"
-            } else {
-                "aoc_benchmark"
-            },
-        )
-        .replace(
-            "{INPUTS}",
-            &template_input(day, year, args.input.as_deref()),
-        );
+        .replace("{BENCHMARKS}", benchmark_fns)
+        .replace("{INPUTS}", &inputs);
 
     fs::create_dir_all("target/aoc/aoc-autobench/benches")
         .expect("failed to create autobench directory");
@@ -536,16 +505,56 @@ pub fn execute_bench(args: &Bench) -> Result<(), Box<dyn error::Error>> {
     )
     .expect("failed to write src/aoc_benchmark.rs");
 
-    let status = process::Command::new("cargo")
-        .args(["bench"])
-        .current_dir("target/aoc/aoc-autobench")
-        .spawn()
-        .expect("Failed to run cargo")
-        .wait()
-        .expect("Failed to wait for cargo");
+    // The allocations are counted by a bench of their own, so they don't slow down the others.
+    // It has the same harness interface as the built-in backend, and reuses its templates.
+    if args.alloc {
+        let builtin = Backend::Builtin.templates();
+        let alloc_content = include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/template/bench/alloc.rs.tpl"
+        ))
+        .replace("{CRATE_SLUG}", &pm.slug)
+        .replace(
+            "{PARTS}",
+            &(builtin.render(&solvers, input) + &builtin.render(&end_to_end, input)),
+        )
+        .replace("{GENS}", &builtin.render(&generators, input))
+        .replace("{BENCHMARKS}", benchmark_fns)
+        .replace("{INPUTS}", &inputs);
 
-    if !status.success() {
-        process::exit(status.code().unwrap_or(-1));
+        fs::write(
+            "target/aoc/aoc-autobench/benches/aoc_allocations.rs",
+            alloc_content,
+        )
+        .expect("failed to write benches/aoc_allocations.rs");
+    }
+
+    let benchmarks = [solvers, end_to_end, generators].concat();
+
+    let dir = Path::new("target/aoc/aoc-autobench");
+    let mut estimates = backend.run(dir, &benchmarks)?;
+    if args.alloc {
+        add_allocations(dir, &mut estimates)?;
+    }
+    print_summary(backend, &estimates);
+
+    Entry::from_bench(backend, &estimates).append()?;
+
+    if args.all {
+        let budgets = Budgets {
+            day: args.day_budget.or(pm.day_budget),
+            year: args.year_budget.or(pm.year_budget),
+        };
+        print_year(year, &estimates, budgets);
+    }
+
+    let regressions = baseline.map_or(0, |(name, baseline)| {
+        baseline.compare(name, backend, &estimates, args.max_regression)
+    });
+
+    if let Some(name) = &args.save_baseline {
+        Baseline::save(name, backend, &estimates)?;
+        println!("\nSaved baseline {}", name);
     }
 
     if args.open {
@@ -557,24 +566,331 @@ pub fn execute_bench(args: &Bench) -> Result<(), Box<dyn error::Error>> {
         webbrowser::open(index)?;
     }
 
+    if regressions > 0 {
+        eprintln!("\n{} benchmark(s) regressed beyond the threshold", regressions);
+        process::exit(1);
+    }
+
     Ok(())
 }
 
-fn template_input(day: Day, year: u32, input: Option<&str>) -> String {
-    let day = day.0.to_string();
-    let path = input
-        .map(|p| {
-            if Path::new(p).is_relative() {
-                format!("../../../../{}", p)
-            } else {
-                p.to_string()
+pub fn execute_history(args: &History) -> Result<(), Box<dyn error::Error>> {
+    history::print(args.day, args.part, args.last)
+}
+
+pub fn execute_readme(args: &Readme) -> Result<(), Box<dyn error::Error>> {
+    let pm = ProjectManager::new()?;
+
+    let day_parts = pm.build_project()?;
+    let year = day_parts.year;
+
+    // Where each solution is defined, as listed by the runner
+    write_runner(&pm, false, false);
+    let output = process::Command::new("cargo")
+        .args([
+            "run",
+            "--release",
+            "--quiet",
+            "--manifest-path",
+            "target/aoc/aoc-autobuild/Cargo.toml",
+            "--",
+            "--list",
+            "--format",
+            "json",
+        ])
+        .stderr(Stdio::inherit())
+        .output()
+        .expect("Failed to run cargo");
+
+    if !output.status.success() {
+        process::exit(output.status.code().unwrap_or(-1));
+    }
+
+    let root = env::current_dir()?;
+    let mut files: Vec<(u32, String)> = Vec::new();
+
+    for line in String::from_utf8(output.stdout)?.lines() {
+        let solution: Value = serde_json::from_str(line)?;
+        let day = solution["day"].as_u64().unwrap_or_default() as u32;
+        let file = Path::new(solution["file"].as_str().unwrap_or_default());
+        let file = file
+            .strip_prefix(&root)
+            .unwrap_or(file)
+            .to_string_lossy()
+            .replace('\\', "/");
+
+        if !files.contains(&(day, file.clone())) {
+            files.push((day, file));
+        }
+    }
+
+    let times = history::latest_times()?;
+    let mut titles = Titles::load()?;
+
+    let mut days: Vec<_> = day_parts.iter().map(|dp| dp.day).collect();
+    days.dedup();
+
+    let mut rows = Vec::new();
+    let mut offline = false;
+    for day in days {
+        let date = AOCDate {
+            day: u32::from(day.0),
+            year: year as i32,
+        };
+        let parts: Vec<_> = day_parts.iter().filter(|dp| dp.day == day).collect();
+        let time = |part: u8| {
+            parts
+                .iter()
+                .filter(|dp| dp.part.0 == part)
+                .filter_map(|dp| times.get(*dp))
+                .min()
+                .copied()
+        };
+
+        let mut stars: Vec<_> = parts.iter().map(|dp| dp.part).collect();
+        stars.dedup();
+
+        // Left for the next run when offline
+        let title = titles
+            .get(date, |date| match offline {
+                true => Err("offline".into()),
+                false => fetch_title(date),
+            })
+            .unwrap_or_else(|e| {
+                if !offline {
+                    eprintln!("Could not fetch the titles of the puzzles: {}", e);
+                    offline = true;
+                }
+                format!("Day {}", date.day)
+            });
+
+        rows.push(Row {
+            date,
+            title,
+            stars: stars.len(),
+            files: files
+                .iter()
+                .filter(|(d, _)| *d == date.day)
+                .map(|(_, file)| file.clone())
+                .collect(),
+            times: [time(1), time(2)],
+        });
+    }
+
+    titles.save()?;
+
+    // The last star is given for all the others
+    let days = date::days(year);
+    let total = 2 * days as usize;
+    let mut stars: usize = rows.iter().map(|row| row.stars).sum();
+    if let Some(last) = rows.last_mut() {
+        if stars == total - 1 && last.date.day == days {
+            last.stars += 1;
+            stars += 1;
+        }
+    }
+
+    let section = readme::render(&rows, &args.badge.to_string_lossy());
+    let appended = readme::update(&args.readme, &section)?;
+    fs::write(&args.badge, readme::badge(stars, total))?;
+
+    println!(
+        "{} the progress of {} days ({}/{} stars) {} {}, and the badge in {}",
+        if appended { "Appended" } else { "Updated" },
+        rows.len(),
+        stars,
+        total,
+        if appended { "to" } else { "in" },
+        args.readme.display(),
+        args.badge.display()
+    );
+
+    Ok(())
+}
+
+/// Fetches the title of a puzzle, from its page.
+fn fetch_title(date: AOCDate) -> Result<String, Box<dyn error::Error>> {
+    let response = reqwest::blocking::Client::new()
+        .get(date.puzzle_url())
+        .header(USER_AGENT, CARGO_AOC_USER_AGENT)
+        .send()?;
+
+    match response.status() {
+        StatusCode::OK => readme::parse_title(&response.text()?)
+            .ok_or_else(|| format!("no title found in {}", date.puzzle_url()).into()),
+        sc => Err(format!(
+            "Could not fetch the title of day {}. Status: {}",
+            date.day, sc
+        )
+        .into()),
+    }
+}
+
+pub fn execute_status() -> Result<(), Box<dyn error::Error>> {
+    let pm = ProjectManager::new()?;
+
+    let day_parts = pm.build_project()?;
+    let year = day_parts.year;
+
+    let token = CredentialsManager::new().get_session_token()?;
+    let mut site = BTreeMap::new();
+
+    let calendar = fetch_page(&format!("https://adventofcode.com/{}", year), &token)?;
+    status::parse_calendar(&calendar, &mut site)?;
+
+    // The personal stats only have a table once a star is collected
+    if site.values().any(|day| day.stars > 0) {
+        let stats = fetch_page(
+            &format!("https://adventofcode.com/{}/leaderboard/self", year),
+            &token,
+        )?;
+        status::parse_stats(&stats, &mut site)?;
+    }
+
+    status::print(year, &site, &day_parts);
+
+    Ok(())
+}
+
+/// Fetches a page of the site, logged in with the session token.
+fn fetch_page(url: &str, token: &str) -> Result<String, Box<dyn error::Error>> {
+    let response = reqwest::blocking::Client::new()
+        .get(url)
+        .header(USER_AGENT, CARGO_AOC_USER_AGENT)
+        .header(COOKIE, format!("session={}", token))
+        .send()?;
+
+    // Redirected to the login page without a valid session
+    if response.url().path().starts_with("/auth") {
+        return Err("Not logged in, is the token correct ?".into());
+    }
+
+    match response.status() {
+        StatusCode::OK => Ok(response.text()?),
+        sc => Err(format!("Could not fetch {}. Status: {}", url, sc).into()),
+    }
+}
+
+pub fn execute_profile(args: &Profile) -> Result<(), Box<dyn error::Error>> {
+    let pm = ProjectManager::new()?;
+
+    let day_parts = pm.build_project()?;
+
+    let day = args
+        .day
+        .unwrap_or_else(|| day_parts.last().expect("No implementation found").day);
+    let part = match args.part {
+        Some(part) => part,
+        None => {
+            day_parts
+                .iter()
+                .rfind(|dp| dp.day == day)
+                .ok_or("No matching day & part found")?
+                .part
+        }
+    };
+
+    if !day_parts.iter().any(|dp| {
+        dp.day == day
+            && dp.part == part
+            && match (&dp.name, &args.name) {
+                (name, None) => name.is_none(),
+                (Some(name), Some(wanted)) => name.eq_ignore_ascii_case(wanted),
+                (None, Some(_)) => false,
             }
-        })
-        .unwrap_or_else(|| format!("../../../../input/{}/day{}.txt", year, &day));
+    }) {
+        return Err("No matching day & part found".into());
+    }
+
+    let date = AOCDate {
+        day: u32::from(day.0),
+        year: day_parts.year as i32,
+    };
+
+    if args.input.is_none() {
+        download_input(date)?;
+    }
+
+    let cargo_content = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/template/profile/Cargo.toml.tpl"
+    ))
+    .replace("{CRATE_NAME}", &pm.name);
+    let main_content = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/template/profile/main.rs.tpl"
+    ))
+    .replace("{CRATE_SLUG}", &pm.slug);
+
+    fs::create_dir_all("target/aoc/aoc-autoprofile/src")
+        .expect("failed to create autoprofile directory");
+    write_if_changed("target/aoc/aoc-autoprofile/Cargo.toml", &cargo_content)
+        .expect("failed to write Cargo.toml");
+    write_if_changed("target/aoc/aoc-autoprofile/src/main.rs", &main_content)
+        .expect("failed to write src/main.rs");
+
+    let output = match &args.name {
+        Some(name) => format!(
+            "target/aoc/profile/day{}_part{}_{}",
+            day.0,
+            part.0,
+            name.to_lowercase()
+        ),
+        None => format!("target/aoc/profile/day{}_part{}", day.0, part.0),
+    };
+
+    let mut profiler_args = vec![
+        "run".to_string(),
+        "--release".to_string(),
+        "--manifest-path".to_string(),
+        "target/aoc/aoc-autoprofile/Cargo.toml".to_string(),
+        "--".to_string(),
+        "--day".to_string(),
+        day.0.to_string(),
+        "--part".to_string(),
+        part.0.to_string(),
+        "--seconds".to_string(),
+        args.duration.as_secs_f64().to_string(),
+        "--frequency".to_string(),
+        args.frequency.to_string(),
+        "--top".to_string(),
+        args.top.to_string(),
+        "--output".to_string(),
+        output,
+    ];
+    if let Some(name) = &args.name {
+        profiler_args.extend(["--name".to_string(), name.clone()]);
+    }
+    if let Some(input) = &args.input {
+        profiler_args.extend(["--input".to_string(), input.clone()]);
+    }
+    for param in &args.params {
+        profiler_args.extend(["--param".to_string(), param.clone()]);
+    }
+
+    let status = process::Command::new("cargo")
+        .args(&profiler_args)
+        .spawn()
+        .expect("Failed to run cargo")
+        .wait()
+        .expect("Failed to wait for cargo");
+
+    if !status.success() {
+        process::exit(status.code().unwrap_or(-1));
+    }
+    Ok(())
+}
+
+fn template_input(date: &AOCDate, input: Option<&str>) -> String {
+    // Read at runtime by the benchmark, which runs from its own directory
+    let path = env::current_dir()
+        .expect("failed to get the current directory")
+        .join(input.map_or_else(|| date.filename(), String::from));
+
     include_str!(concat!(
         env!("CARGO_MANIFEST_DIR"),
         "/template/input.rs.tpl"
     ))
-    .replace("{PATH}", &path)
-    .replace("{DAY}", &day)
+    .replace("{PATH}", &format!("{:?}", path.display().to_string()))
+    .replace("{DAY}", &date.day.to_string())
 }
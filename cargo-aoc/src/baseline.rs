@@ -0,0 +1,348 @@
+use crate::bench::{Backend, Benchmark, Estimate, Kind};
+use aoc_runner_internal::DayPart;
+use serde::{Deserialize, Serialize};
+use std::error;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Estimates saved by `cargo aoc bench --save-baseline`, in `target/aoc/baselines/{name}.json`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    estimates: Vec<Saved>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Saved {
+    day_part: DayPart,
+    kind: Kind,
+    backend: String,
+    median_ns: u64,
+    mean_ns: u64,
+}
+
+impl Baseline {
+    fn path(name: &str) -> Result<PathBuf, Box<dyn error::Error>> {
+        if name.is_empty() || name.contains(['/', '\\']) || name.starts_with('.') {
+            return Err(format!("invalid baseline name: {:?}", name).into());
+        }
+
+        Ok(PathBuf::from(format!("target/aoc/baselines/{}.json", name)))
+    }
+
+    pub fn load(name: &str) -> Result<Baseline, Box<dyn error::Error>> {
+        let content = fs::read_to_string(Baseline::path(name)?).map_err(|e| {
+            if e.kind() == io::ErrorKind::NotFound {
+                format!(
+                    "no baseline named {}, save one with `cargo aoc bench --save-baseline {}`",
+                    name, name
+                )
+                .into()
+            } else {
+                Box::<dyn error::Error>::from(e)
+            }
+        })?;
+
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Saves the estimates, keeping the other benchmarks of the baseline (e.g. other days).
+    pub fn save(
+        name: &str,
+        backend: Backend,
+        estimates: &[Estimate],
+    ) -> Result<(), Box<dyn error::Error>> {
+        let mut baseline = match Baseline::load(name) {
+            Ok(baseline) => baseline,
+            Err(_) if !Baseline::path(name)?.exists() => Baseline::default(),
+            Err(e) => return Err(e),
+        };
+
+        baseline.estimates.retain(|saved| {
+            !estimates
+                .iter()
+                .any(|estimate| saved.is(&estimate.benchmark))
+        });
+        baseline
+            .estimates
+            .extend(estimates.iter().map(|estimate| Saved {
+                day_part: estimate.benchmark.day_part.clone(),
+                kind: estimate.benchmark.kind,
+                backend: backend.to_string(),
+                median_ns: estimate.median.as_nanos() as u64,
+                mean_ns: estimate.mean.as_nanos() as u64,
+            }));
+        baseline
+            .estimates
+            .sort_by(|a, b| (a.kind, &a.day_part).cmp(&(b.kind, &b.day_part)));
+
+        let path = Baseline::path(name)?;
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(path, serde_json::to_string_pretty(&baseline)?)?;
+
+        Ok(())
+    }
+
+    /// Prints the change of the median time of each benchmark since the baseline.
+    ///
+    /// Returns the number of benchmarks slower than the baseline by more than `max_regression`
+    /// percent.
+    pub fn compare(
+        &self,
+        name: &str,
+        backend: Backend,
+        estimates: &[Estimate],
+        max_regression: Option<f64>,
+    ) -> usize {
+        let width = estimates
+            .iter()
+            .map(|e| e.benchmark.to_string().len())
+            .max()
+            .unwrap_or(0);
+
+        match max_regression {
+            Some(max) => println!("\nCompared to {} (max regression {}%):", name, max),
+            None => println!("\nCompared to {}:", name),
+        }
+
+        let mut regressions = 0;
+
+        for estimate in estimates {
+            let label = estimate.benchmark.to_string();
+
+            match self.change(backend, estimate, max_regression) {
+                Change::Missing => {
+                    println!("{:<width$} : not in the baseline", label, width = width)
+                }
+                Change::Measured {
+                    before,
+                    delta,
+                    regressed,
+                    other_backend,
+                } => {
+                    let measured_with = match other_backend {
+                        Some(backend) => format!(" (measured with {})", backend),
+                        None => String::new(),
+                    };
+
+                    println!(
+                        "{:<width$} : {:>12?} -> {:>12?} {:>+8.2}%{}{}",
+                        label,
+                        before,
+                        estimate.median,
+                        delta,
+                        measured_with,
+                        if regressed { "  REGRESSION" } else { "" },
+                        width = width
+                    );
+
+                    regressions += usize::from(regressed);
+                }
+            }
+        }
+
+        regressions
+    }
+
+    /// The change of the median time of a benchmark since the baseline.
+    fn change(
+        &self,
+        backend: Backend,
+        estimate: &Estimate,
+        max_regression: Option<f64>,
+    ) -> Change<'_> {
+        let Some(saved) = self.estimates.iter().find(|s| s.is(&estimate.benchmark)) else {
+            return Change::Missing;
+        };
+
+        let before = Duration::from_nanos(saved.median_ns);
+        let delta = 100.0 * (estimate.median.as_secs_f64() / before.as_secs_f64() - 1.0);
+
+        Change::Measured {
+            before,
+            delta,
+            regressed: max_regression.is_some_and(|max| delta > max),
+            other_backend: Some(saved.backend.as_str())
+                .filter(|saved| *saved != backend.to_string()),
+        }
+    }
+}
+
+/// How a benchmark changed since the baseline.
+#[derive(Debug)]
+enum Change<'a> {
+    /// The benchmark isn't in the baseline.
+    Missing,
+    Measured {
+        /// The median time in the baseline.
+        before: Duration,
+        /// The change of the median time, in percent.
+        delta: f64,
+        /// Whether it's slower than allowed by `--max-regression`.
+        regressed: bool,
+        /// The backend of the baseline, if it's another one.
+        other_backend: Option<&'a str>,
+    },
+}
+
+impl Saved {
+    fn is(&self, benchmark: &Benchmark) -> bool {
+        self.day_part == benchmark.day_part && self.kind == benchmark.kind
+    }
+}
+
+/// Parses a percentage, like `10%` or `2.5`.
+pub fn parse_percent(percent: &str) -> Result<f64, String> {
+    percent
+        .trim_end_matches('%')
+        .parse()
+        .ok()
+        .filter(|p: &f64| p.is_finite() && *p >= 0.0)
+        .ok_or_else(|| format!("invalid percentage: {}", percent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_runner_internal::{Day, Part};
+
+    fn benchmark(day: u8, kind: Kind) -> Benchmark {
+        Benchmark {
+            day_part: DayPart {
+                day: Day(day),
+                part: Part(1),
+                name: None,
+            },
+            kind,
+        }
+    }
+
+    fn estimate(day: u8, kind: Kind, median_ms: u64) -> Estimate {
+        Estimate {
+            benchmark: benchmark(day, kind),
+            median: Duration::from_millis(median_ms),
+            mean: Duration::from_millis(median_ms),
+            allocs: None,
+        }
+    }
+
+    /// A baseline of the solvers of the given days, with their median times.
+    fn baseline(backend: Backend, medians_ms: &[(u8, u64)]) -> Baseline {
+        Baseline {
+            estimates: medians_ms
+                .iter()
+                .map(|&(day, median_ms)| Saved {
+                    day_part: benchmark(day, Kind::Solver).day_part,
+                    kind: Kind::Solver,
+                    backend: backend.to_string(),
+                    median_ns: median_ms * 1_000_000,
+                    mean_ns: median_ms * 1_000_000,
+                })
+                .collect(),
+        }
+    }
+
+    fn regressed(baseline: &Baseline, median_ms: u64, max_regression: Option<f64>) -> bool {
+        match baseline.change(
+            Backend::Criterion,
+            &estimate(1, Kind::Solver, median_ms),
+            max_regression,
+        ) {
+            Change::Measured { regressed, .. } => regressed,
+            Change::Missing => panic!("expected a change"),
+        }
+    }
+
+    #[test]
+    fn parse_percentages() {
+        assert_eq!(parse_percent("10%"), Ok(10.0));
+        assert_eq!(parse_percent("10"), Ok(10.0));
+        assert_eq!(parse_percent("2.5%"), Ok(2.5));
+        assert_eq!(parse_percent("0"), Ok(0.0));
+        assert_eq!(parse_percent("-5%"), Err("invalid percentage: -5%".into()));
+        assert_eq!(parse_percent("ten"), Err("invalid percentage: ten".into()));
+        assert_eq!(parse_percent("inf"), Err("invalid percentage: inf".into()));
+        assert_eq!(parse_percent(""), Err("invalid percentage: ".into()));
+    }
+
+    #[test]
+    fn regressions_beyond_the_threshold() {
+        let baseline = baseline(Backend::Criterion, &[(1, 100)]);
+
+        match baseline.change(
+            Backend::Criterion,
+            &estimate(1, Kind::Solver, 120),
+            Some(10.0),
+        ) {
+            Change::Measured { before, delta, .. } => {
+                assert_eq!(before, Duration::from_millis(100));
+                assert!((delta - 20.0).abs() < 1e-9);
+            }
+            Change::Missing => panic!("expected a change"),
+        }
+
+        assert!(regressed(&baseline, 120, Some(10.0)));
+        assert!(!regressed(&baseline, 120, Some(25.0)));
+        assert!(!regressed(&baseline, 120, None));
+        assert!(!regressed(&baseline, 80, Some(0.0)));
+        assert!(!regressed(&baseline, 100, Some(0.0)));
+    }
+
+    #[test]
+    fn counts_regressions_and_skips_benchmarks_missing_from_the_baseline() {
+        let baseline = baseline(Backend::Criterion, &[(1, 100), (2, 100)]);
+
+        assert!(matches!(
+            baseline.change(
+                Backend::Criterion,
+                &estimate(3, Kind::Solver, 500),
+                Some(10.0)
+            ),
+            Change::Missing
+        ));
+        assert!(matches!(
+            baseline.change(
+                Backend::Criterion,
+                &estimate(1, Kind::Generator, 500),
+                Some(10.0)
+            ),
+            Change::Missing
+        ));
+
+        let estimates = [
+            estimate(1, Kind::Solver, 150),
+            estimate(2, Kind::Solver, 105),
+            estimate(3, Kind::Solver, 500),
+        ];
+        assert_eq!(
+            baseline.compare("main", Backend::Criterion, &estimates, Some(10.0)),
+            1
+        );
+        assert_eq!(
+            baseline.compare("main", Backend::Criterion, &estimates, None),
+            0
+        );
+    }
+
+    #[test]
+    fn flags_baselines_of_other_backends() {
+        let baseline = baseline(Backend::Divan, &[(1, 100)]);
+        let estimate = estimate(1, Kind::Solver, 100);
+
+        assert!(matches!(
+            baseline.change(Backend::Criterion, &estimate, None),
+            Change::Measured {
+                other_backend: Some("divan"),
+                ..
+            }
+        ));
+        assert!(matches!(
+            baseline.change(Backend::Divan, &estimate, None),
+            Change::Measured {
+                other_backend: None,
+                ..
+            }
+        ));
+    }
+}
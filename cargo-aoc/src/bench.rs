@@ -0,0 +1,602 @@
+use aoc_runner::alloc::{Allocs, Bytes};
+use aoc_runner_internal::{Day, DayPart};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::process::{self, Stdio};
+use std::time::Duration;
+
+/// The harness measuring the benchmarks generated by `cargo aoc bench`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Backend {
+    /// Criterion, with HTML reports (if gnuplot is installed).
+    #[default]
+    Criterion,
+    /// Divan, faster to compile.
+    Divan,
+    /// A minimal harness, without dependencies.
+    Builtin,
+}
+
+/// The templates of the benchmark crate of a backend.
+pub struct Templates {
+    pub cargo: &'static str,
+    pub bench: &'static str,
+    pub part: &'static str,
+    pub gen: &'static str,
+    pub impl_: &'static str,
+    pub gen_impl: &'static str,
+    pub e2e: &'static str,
+    pub e2e_impl: &'static str,
+}
+
+macro_rules! templates {
+    ($backend:literal) => {
+        Templates {
+            cargo: include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/template/bench/",
+                $backend,
+                "/Cargo.toml.tpl"
+            )),
+            bench: include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/template/bench/",
+                $backend,
+                "/aoc_benchmark.rs.tpl"
+            )),
+            part: include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/template/bench/",
+                $backend,
+                "/part.rs.tpl"
+            )),
+            gen: include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/template/bench/",
+                $backend,
+                "/gen.rs.tpl"
+            )),
+            impl_: include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/template/bench/",
+                $backend,
+                "/impl.rs.tpl"
+            )),
+            gen_impl: include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/template/bench/",
+                $backend,
+                "/gen_impl.rs.tpl"
+            )),
+            e2e: include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/template/bench/",
+                $backend,
+                "/e2e.rs.tpl"
+            )),
+            e2e_impl: include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/template/bench/",
+                $backend,
+                "/e2e_impl.rs.tpl"
+            )),
+        }
+    };
+}
+
+/// A benchmark of a solution, of its generator, or of both.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Benchmark {
+    pub day_part: DayPart,
+    pub kind: Kind,
+}
+
+/// What a benchmark measures.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Kind {
+    Solver,
+    Generator,
+    /// The generator then the solver, from the raw input.
+    EndToEnd,
+}
+
+/// Time limits of `cargo aoc bench --all`, for the end-to-end time of each day and their sum.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Budgets {
+    pub day: Option<Duration>,
+    pub year: Option<Duration>,
+}
+
+/// The time of a benchmark, as estimated by the backend.
+#[derive(Clone, Debug)]
+pub struct Estimate {
+    pub benchmark: Benchmark,
+    pub median: Duration,
+    pub mean: Duration,
+    /// The allocations of a run, with `--alloc`, counted by the `aoc_allocations` bench.
+    pub allocs: Option<Allocs>,
+}
+
+impl Templates {
+    /// Renders the groups of `benchmarks` (of a single kind, sorted by day & part), reading the
+    /// input of each day with `input`.
+    pub fn render(&self, benchmarks: &[Benchmark], input: impl Fn(Day) -> String) -> String {
+        benchmarks
+            .chunk_by(|a, b| {
+                let (a, b) = (&a.day_part, &b.day_part);
+                (a.day, a.part) == (b.day, b.part)
+            })
+            .map(|group| {
+                let DayPart { day, part, .. } = group[0].day_part;
+                let part_name = format!("day{}_part{}", day.0, part.0);
+                let gen_name = format!("day{}", day.0);
+
+                let (group_tpl, impl_tpl) = match group[0].kind {
+                    Kind::Solver => (self.part, self.impl_),
+                    Kind::Generator => (self.gen, self.gen_impl),
+                    Kind::EndToEnd => (self.e2e, self.e2e_impl),
+                };
+
+                let impls: String = group
+                    .iter()
+                    .map(|benchmark| {
+                        impl_tpl
+                            .replace("{RUNNER_NAME}", &benchmark.runner_name())
+                            .replace("{INPUTS}", &input(day))
+                            .replace("{DAY}", &day.0.to_string())
+                            .replace("{NAME}", &benchmark.function())
+                            .replace("{PART_NAME}", &part_name)
+                            .replace("{GEN_NAME}", &gen_name)
+                    })
+                    .collect();
+
+                group_tpl
+                    .replace("{PART_NAME}", &part_name)
+                    .replace("{GEN_NAME}", &gen_name)
+                    .replace("{DAY}", &day.0.to_string())
+                    .replace("{PART}", &part.0.to_string())
+                    .replace("{IMPLS}", &impls)
+            })
+            .collect()
+    }
+}
+
+impl Backend {
+    pub fn templates(self) -> Templates {
+        match self {
+            Backend::Criterion => templates!("criterion"),
+            Backend::Divan => templates!("divan"),
+            Backend::Builtin => templates!("builtin"),
+        }
+    }
+
+    /// Runs the benchmark crate in `dir`, and reads the estimates of `benchmarks`.
+    pub fn run(
+        self,
+        dir: &Path,
+        benchmarks: &[Benchmark],
+    ) -> Result<Vec<Estimate>, Box<dyn error::Error>> {
+        let mut cargo = process::Command::new("cargo");
+        cargo.arg("bench").current_dir(dir);
+
+        let times = match self {
+            Backend::Criterion => {
+                wait(cargo.spawn()?)?;
+                criterion_estimates(&dir.join("target/criterion"))?
+            }
+            Backend::Builtin => {
+                wait(cargo.spawn()?)?;
+                builtin_estimates(&dir.join("target/builtin/estimates.jsonl"))?
+            }
+            Backend::Divan => {
+                // Divan only prints its estimates, they're saved while forwarded
+                let mut child = cargo.stdout(Stdio::piped()).spawn()?;
+                let mut output = String::new();
+
+                for line in BufReader::new(child.stdout.take().unwrap()).lines() {
+                    let line = line?;
+                    println!("{}", line);
+                    output.push_str(&line);
+                    output.push('\n');
+                }
+
+                wait(child)?;
+
+                let path = dir.join("target/divan/output.txt");
+                fs::create_dir_all(path.parent().unwrap())?;
+                fs::write(&path, &output)?;
+
+                divan_estimates(&output)?
+            }
+        };
+
+        benchmarks
+            .iter()
+            .map(|benchmark| {
+                let (median, mean) = times
+                    .get(&(benchmark.group(), benchmark.function()))
+                    .ok_or_else(|| format!("no estimate found for {}", benchmark))?;
+
+                Ok(Estimate {
+                    benchmark: benchmark.clone(),
+                    median: Duration::from_nanos(*median as u64),
+                    mean: Duration::from_nanos(*mean as u64),
+                    allocs: None,
+                })
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = self.to_possible_value().expect("no skipped backend");
+        f.write_str(name.get_name())
+    }
+}
+
+impl Benchmark {
+    /// The group of the benchmark, as named by the templates.
+    pub fn group(&self) -> String {
+        let DayPart { day, part, .. } = &self.day_part;
+
+        match self.kind {
+            Kind::Solver => format!("Day{} - Part{}", day.0, part.0),
+            Kind::Generator => format!("Generator Day{} - Part{}", day.0, part.0),
+            Kind::EndToEnd => format!("End-to-end Day{} - Part{}", day.0, part.0),
+        }
+    }
+
+    /// The function of the factory building the runner of the solution.
+    fn runner_name(&self) -> String {
+        let DayPart { day, part, name } = &self.day_part;
+
+        match name {
+            Some(name) => format!("day{}_part{}_{}", day.0, part.0, name.to_lowercase()),
+            None => format!("day{}_part{}", day.0, part.0),
+        }
+    }
+
+    /// The name of the benchmark in its group.
+    pub fn function(&self) -> String {
+        self.day_part
+            .name
+            .clone()
+            .unwrap_or_else(|| "(default)".to_string())
+    }
+}
+
+impl fmt::Display for Benchmark {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let DayPart { day, part, name } = &self.day_part;
+
+        write!(f, "Day {} - Part {}", day.0, part.0)?;
+        if let Some(name) = name {
+            write!(f, " - {}", name)?;
+        }
+        match self.kind {
+            Kind::Solver => Ok(()),
+            Kind::Generator => write!(f, " (generator)"),
+            Kind::EndToEnd => write!(f, " (end-to-end)"),
+        }
+    }
+}
+
+/// Prints the estimates the same way for every backend.
+pub fn print_summary(backend: Backend, estimates: &[Estimate]) {
+    let width = estimates
+        .iter()
+        .map(|e| e.benchmark.to_string().len())
+        .max()
+        .unwrap_or(0);
+
+    println!("\nSummary ({}):", backend);
+    for estimate in estimates {
+        println!(
+            "{:<width$} : median {:>12?}, mean {:>12?}{}",
+            estimate.benchmark.to_string(),
+            estimate.median,
+            estimate.mean,
+            estimate.allocs.map_or(String::new(), |allocs| format!(
+                ", {:>6} allocs, {:>10} allocated, {:>10} peak",
+                allocs.count,
+                Bytes(allocs.bytes),
+                Bytes(allocs.peak)
+            )),
+            width = width
+        );
+    }
+}
+
+/// Adds the allocations counted by the `aoc_allocations` bench of the crate in `dir` to the
+/// estimates.
+pub fn add_allocations(
+    dir: &Path,
+    estimates: &mut [Estimate],
+) -> Result<(), Box<dyn error::Error>> {
+    let mut allocations = HashMap::new();
+
+    for line in fs::read_to_string(dir.join("target/alloc/allocations.jsonl"))?.lines() {
+        let counted: Value = serde_json::from_str(line)?;
+        let id = |key: &str| counted[key].as_str().unwrap_or_default().to_string();
+        let count = |key: &str| counted[key].as_u64().unwrap_or_default();
+
+        allocations.insert(
+            (id("group"), id("function")),
+            Allocs {
+                count: count("allocs"),
+                bytes: count("alloc_bytes"),
+                peak: count("peak_bytes"),
+            },
+        );
+    }
+
+    for estimate in estimates {
+        let id = (estimate.benchmark.group(), estimate.benchmark.function());
+        estimate.allocs = Some(
+            *allocations
+                .get(&id)
+                .ok_or_else(|| format!("no allocations counted for {}", estimate.benchmark))?,
+        );
+    }
+
+    Ok(())
+}
+
+/// Prints the end-to-end time of each day, its share of the year, and the days over budget.
+pub fn print_year(year: u32, estimates: &[Estimate], budgets: Budgets) {
+    let mut days: Vec<(Day, Duration)> = Vec::new();
+
+    for estimate in estimates.iter().filter(|e| e.benchmark.kind == Kind::EndToEnd) {
+        match days.last_mut() {
+            Some((day, total)) if *day == estimate.benchmark.day_part.day => {
+                *total += estimate.median
+            }
+            _ => days.push((estimate.benchmark.day_part.day, estimate.median)),
+        }
+    }
+
+    let total: Duration = days.iter().map(|(_, time)| *time).sum();
+    let over = |time: Duration, budget: Option<Duration>| match budget {
+        Some(budget) if time > budget => format!("  OVER BUDGET ({:?})", budget),
+        _ => String::new(),
+    };
+
+    println!("\nYear {} (end-to-end median of each part):", year);
+    for (day, time) in &days {
+        let share = time.as_secs_f64() / total.as_secs_f64().max(f64::MIN_POSITIVE);
+
+        let line = format!(
+            "Day {:>2} : {:>12?} {:>6.1}% {:<30}{}",
+            day.0,
+            time,
+            100.0 * share,
+            "#".repeat((30.0 * share).round() as usize),
+            over(*time, budgets.day)
+        );
+        println!("{}", line.trim_end());
+    }
+    println!("Total  : {:>12?}{}", total, over(total, budgets.year));
+}
+
+/// Parses a duration, like `1s`, `250ms`, `1.5ms` or `800us`.
+pub fn parse_duration(duration: &str) -> Result<Duration, String> {
+    let split = duration
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(duration.len());
+    let (value, unit) = duration.split_at(split);
+
+    let factor = match unit.trim() {
+        "ns" => 1e-9,
+        "us" | "µs" => 1e-6,
+        "ms" => 1e-3,
+        "s" => 1.0,
+        _ => return Err(format!("invalid duration: {} (expected e.g. 1s or 250ms)", duration)),
+    };
+
+    value
+        .parse::<f64>()
+        .map(|value| Duration::from_secs_f64(value * factor))
+        .map_err(|_| format!("invalid duration: {}", duration))
+}
+
+fn wait(mut child: process::Child) -> Result<(), Box<dyn error::Error>> {
+    let status = child.wait()?;
+
+    if !status.success() {
+        process::exit(status.code().unwrap_or(-1));
+    }
+    Ok(())
+}
+
+/// Median & mean times in nanoseconds, by group & function.
+type Times = HashMap<(String, String), (f64, f64)>;
+
+/// Reads `{group}/{function}/new/{benchmark,estimates}.json` in the output of Criterion.
+fn criterion_estimates(dir: &Path) -> Result<Times, Box<dyn error::Error>> {
+    let mut times = Times::new();
+
+    for group in fs::read_dir(dir)? {
+        for function in fs::read_dir(group?.path())? {
+            let new = function?.path().join("new");
+
+            let (Ok(benchmark), Ok(estimates)) = (
+                fs::read_to_string(new.join("benchmark.json")),
+                fs::read_to_string(new.join("estimates.json")),
+            ) else {
+                continue;
+            };
+
+            let benchmark: Value = serde_json::from_str(&benchmark)?;
+            let estimates: Value = serde_json::from_str(&estimates)?;
+
+            let id = |key: &str| benchmark[key].as_str().unwrap_or_default().to_string();
+            let point = |key: &str| estimates[key]["point_estimate"].as_f64().unwrap_or(f64::NAN);
+
+            times.insert(
+                (id("group_id"), id("function_id")),
+                (point("median"), point("mean")),
+            );
+        }
+    }
+
+    Ok(times)
+}
+
+/// Reads the lines written by the built-in harness.
+fn builtin_estimates(path: &Path) -> Result<Times, Box<dyn error::Error>> {
+    let mut times = Times::new();
+
+    for line in fs::read_to_string(path)?.lines() {
+        let estimate: Value = serde_json::from_str(line)?;
+        let id = |key: &str| estimate[key].as_str().unwrap_or_default().to_string();
+        let time = |key: &str| estimate[key].as_f64().unwrap_or(f64::NAN);
+
+        times.insert(
+            (id("group"), id("function")),
+            (time("median_ns"), time("mean_ns")),
+        );
+    }
+
+    Ok(times)
+}
+
+/// Parses the tree printed by Divan :
+/// ```text
+/// aoc_benchmark        fastest   │ slowest   │ median    │ mean      │ samples │ iters
+/// ╰─ Day1 - Part1                │           │           │           │         │
+///    ├─ (default)      7.1 µs    │ 9.8 µs    │ 7.3 µs    │ 7.4 µs    │ 100     │ 100
+/// ```
+///
+/// Fails if the times of a benchmark can't be read, rather than leaving it out of the estimates.
+fn divan_estimates(output: &str) -> Result<Times, Box<dyn error::Error>> {
+    let mut times = Times::new();
+    let mut group = String::new();
+
+    for line in output.lines() {
+        // The branches are drawn with the same character as the columns
+        let Some((indent, entry)) = line
+            .split_once("├─ ")
+            .or_else(|| line.split_once("╰─ "))
+        else {
+            continue;
+        };
+
+        let columns: Vec<_> = entry.split('│').collect();
+        let (name, _) = split_time(columns[0]);
+
+        if indent.is_empty() {
+            group = name.to_string();
+            continue;
+        }
+
+        let time = |column: usize| columns.get(column).and_then(|c| split_time(c).1);
+        let (Some(median), Some(mean)) = (time(2), time(3)) else {
+            return Err(format!(
+                "failed to read the estimates of {}/{} in the output of Divan: {}",
+                group,
+                name,
+                line.trim()
+            )
+            .into());
+        };
+
+        times.insert((group.clone(), name.to_string()), (median, mean));
+    }
+
+    if times.is_empty() {
+        return Err("no estimate found in the output of Divan".into());
+    }
+
+    Ok(times)
+}
+
+/// Splits a column ending with a time (`12.5 µs`), converted to nanoseconds.
+fn split_time(column: &str) -> (&str, Option<f64>) {
+    let column = column.trim();
+
+    let time = column.rsplit_once(' ').and_then(|(rest, unit)| {
+        let factor = match unit {
+            "ps" => 1e-3,
+            "ns" => 1.0,
+            "µs" => 1e3,
+            "ms" => 1e6,
+            "s" => 1e9,
+            _ => return None,
+        };
+        let (rest, value) = rest.trim_end().rsplit_once(' ').unwrap_or(("", rest));
+        Some((rest.trim_end(), value.parse::<f64>().ok()? * factor))
+    });
+
+    match time {
+        Some((rest, time)) => (rest, Some(time)),
+        None => (column, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIVAN_OUTPUT: &str = "\
+Timer precision: 38 ns
+aoc_benchmark              fastest       │ slowest       │ median        │ mean          │ samples │ iters
+├─ Day1 - Part1                          │               │               │               │         │
+│  ╰─ (default)            107.3 ns      │ 126.7 ns      │ 109.5 ns      │ 111.1 ns      │ 100     │ 3200
+├─ Day1 - Part2                          │               │               │               │         │
+│  ├─ (default)            10.13 ms      │ 24.96 ms      │ 14.66 ms      │ 14.63 ms      │ 100     │ 100
+│  ╰─ Fnv                  5.72 ms       │ 10.75 ms      │ 7.852 ms      │ 7.948 ms      │ 100     │ 100
+╰─ Generator Day1 - Part2                │               │               │               │         │
+   ├─ (default)            27.55 µs      │ 72.3 µs       │ 33.42 µs      │ 33.91 µs      │ 100     │ 100
+   ╰─ Fnv                  27.18 µs      │ 108.6 µs      │ 34 µs         │ 36 µs         │ 100     │ 100
+";
+
+    fn estimate(times: &Times, group: &str, function: &str) -> (f64, f64) {
+        let (median, mean) = times[&(group.to_string(), function.to_string())];
+        // Rounded, as the units are converted
+        (median.round(), mean.round())
+    }
+
+    #[test]
+    fn divan_estimates_of_recorded_output() {
+        let times = divan_estimates(DIVAN_OUTPUT).unwrap();
+
+        assert_eq!(times.len(), 5);
+        assert_eq!(estimate(&times, "Day1 - Part1", "(default)"), (110.0, 111.0));
+        assert_eq!(
+            estimate(&times, "Day1 - Part2", "Fnv"),
+            (7_852_000.0, 7_948_000.0)
+        );
+        assert_eq!(
+            estimate(&times, "Generator Day1 - Part2", "(default)"),
+            (33_420.0, 33_910.0)
+        );
+        assert_eq!(
+            estimate(&times, "Generator Day1 - Part2", "Fnv"),
+            (34_000.0, 36_000.0)
+        );
+    }
+
+    #[test]
+    fn divan_estimates_fail_on_unreadable_times() {
+        let output = DIVAN_OUTPUT.replace("7.852 ms", "7.852 ks");
+        let error = divan_estimates(&output).unwrap_err().to_string();
+
+        assert!(error.contains("Day1 - Part2/Fnv"), "{}", error);
+    }
+
+    #[test]
+    fn divan_estimates_fail_without_tree() {
+        let output = DIVAN_OUTPUT.replace("├─", "|-").replace("╰─", "`-");
+
+        assert!(divan_estimates(&output).is_err());
+    }
+}
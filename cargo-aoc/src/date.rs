@@ -3,6 +3,15 @@ use chrono_tz::EST;
 
 use crate::Input;
 
+/// The number of days of a year : 25 until 2024, and 12 since.
+pub fn days(year: u32) -> u32 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct AOCDate {
     /// The day of the input to retrieve
@@ -42,4 +51,9 @@ impl AOCDate {
             self.year, self.day
         )
     }
+
+    /// The URL of the puzzle
+    pub fn puzzle_url(&self) -> String {
+        format!("https://adventofcode.com/{}/day/{}", self.year, self.day)
+    }
 }
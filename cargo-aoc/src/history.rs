@@ -0,0 +1,339 @@
+use crate::bench::{Backend, Benchmark, Estimate, Kind};
+use aoc_runner_internal::{Day, DayPart, Part};
+use chrono::{DateTime, Local, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::error;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+/// Where every run of `cargo aoc` & `cargo aoc bench` is appended, one JSON object per line.
+const PATH: &str = "target/aoc/history.jsonl";
+
+/// The results of a run, and where they were measured.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Entry {
+    /// RFC 3339, in UTC.
+    time: String,
+    /// The commit checked out, if the project is a git repository.
+    commit: Option<String>,
+    /// Whether the working tree had uncommitted changes.
+    dirty: bool,
+    machine: Machine,
+    /// `run`, or the backend of `bench`.
+    source: String,
+    results: Vec<Record>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Machine {
+    os: String,
+    arch: String,
+    cpu: Option<String>,
+    cpus: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Record {
+    day_part: DayPart,
+    kind: Kind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    answer: Option<String>,
+    /// The best time of the runs of `cargo aoc`, or the median estimated by `cargo aoc bench`.
+    time_ns: u64,
+}
+
+impl Entry {
+    fn new(source: String, results: Vec<Record>) -> Entry {
+        let git = |args: &[&str]| {
+            process::Command::new("git")
+                .args(args)
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        };
+
+        let commit = git(&["rev-parse", "HEAD"]);
+        let dirty =
+            commit.is_some() && git(&["status", "--porcelain"]).is_some_and(|s| !s.is_empty());
+
+        Entry {
+            time: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+            commit,
+            dirty,
+            machine: Machine::current(),
+            source,
+            results,
+        }
+    }
+
+    /// The results written by the runner of `cargo aoc` with `--record`.
+    pub fn from_run(path: &Path) -> Result<Entry, Box<dyn error::Error>> {
+        let results = parse_run(&fs::read_to_string(path)?)?;
+
+        Ok(Entry::new("run".to_string(), results))
+    }
+
+    /// The estimates of `cargo aoc bench`.
+    pub fn from_bench(backend: Backend, estimates: &[Estimate]) -> Entry {
+        let results = estimates
+            .iter()
+            .map(|estimate| Record {
+                day_part: estimate.benchmark.day_part.clone(),
+                kind: estimate.benchmark.kind,
+                answer: None,
+                time_ns: estimate.median.as_nanos() as u64,
+            })
+            .collect();
+
+        Entry::new(format!("bench ({})", backend), results)
+    }
+
+    pub fn append(&self) -> Result<(), Box<dyn error::Error>> {
+        fs::create_dir_all(Path::new(PATH).parent().unwrap())?;
+
+        let mut file = OpenOptions::new().create(true).append(true).open(PATH)?;
+        writeln!(file, "{}", serde_json::to_string(self)?)?;
+
+        Ok(())
+    }
+}
+
+/// The records of the solutions and generators of a run, skipping the ones that failed.
+fn parse_run(content: &str) -> Result<Vec<Record>, Box<dyn error::Error>> {
+    let mut results = Vec::new();
+
+    for line in content.lines() {
+        let result: Value = serde_json::from_str(line)?;
+
+        let kind = match (&result["answer"], &result["generator"]) {
+            (Value::String(_), _) => Kind::Solver,
+            (_, Value::String(_)) => Kind::Generator,
+            // Failed
+            _ => continue,
+        };
+
+        results.push(Record {
+            day_part: DayPart {
+                day: Day(result["day"].as_u64().unwrap_or_default() as u8),
+                part: Part(result["part"].as_u64().unwrap_or_default() as u8),
+                name: result["name"].as_str().map(String::from),
+            },
+            kind,
+            answer: result["answer"].as_str().map(String::from),
+            time_ns: result["best_ns"].as_u64().unwrap_or_default(),
+        });
+    }
+
+    Ok(results)
+}
+
+impl Machine {
+    fn current() -> Machine {
+        // Only known on Linux
+        let cpu = fs::read_to_string("/proc/cpuinfo")
+            .ok()
+            .and_then(|cpuinfo| {
+                cpuinfo
+                    .lines()
+                    .find_map(|line| line.strip_prefix("model name")?.split_once(':'))
+                    .map(|(_, model)| model.trim().to_string())
+            });
+
+        Machine {
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            cpu,
+            cpus: thread::available_parallelism().map_or(1, |n| n.get()),
+        }
+    }
+}
+
+/// The entries of the history, none if it doesn't exist yet.
+fn load() -> Result<Vec<Entry>, Box<dyn error::Error>> {
+    let content = match fs::read_to_string(PATH) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    Ok(content
+        .lines()
+        .map(serde_json::from_str)
+        .collect::<Result<_, _>>()?)
+}
+
+/// The latest time of each solver, measured by `cargo aoc` or `cargo aoc bench`.
+pub fn latest_times() -> Result<HashMap<DayPart, Duration>, Box<dyn error::Error>> {
+    let mut times = HashMap::new();
+
+    for entry in load()? {
+        for record in entry.results {
+            if record.kind == Kind::Solver {
+                times.insert(record.day_part, Duration::from_nanos(record.time_ns));
+            }
+        }
+    }
+
+    Ok(times)
+}
+
+/// The results of each solution measured the same way (by a kind & source), in order.
+type Series<'a> = BTreeMap<(DayPart, Kind, &'a str), Vec<(&'a Entry, &'a Record)>>;
+
+/// Prints how the results of each solution of `day` (or every day) changed, the `last` ones only
+/// if given.
+pub fn print(
+    day: Option<Day>,
+    part: Option<Part>,
+    last: Option<usize>,
+) -> Result<(), Box<dyn error::Error>> {
+    let entries = load()?;
+
+    if entries.is_empty() {
+        return Err("no history yet, it is recorded by `cargo aoc` and `cargo aoc bench`".into());
+    }
+
+    let mut series = Series::new();
+
+    for entry in &entries {
+        for record in &entry.results {
+            let dp = &record.day_part;
+            if day.is_none_or(|d| dp.day == d) && part.is_none_or(|p| dp.part == p) {
+                series
+                    .entry((dp.clone(), record.kind, &entry.source))
+                    .or_default()
+                    .push((entry, record));
+            }
+        }
+    }
+
+    if series.is_empty() {
+        return Err("No matching day & part found in the history".into());
+    }
+
+    for ((day_part, kind, source), results) in &series {
+        let results = &results[results.len().saturating_sub(last.unwrap_or(results.len()))..];
+
+        let benchmark = Benchmark {
+            day_part: day_part.clone(),
+            kind: *kind,
+        };
+        let times: Vec<_> = results.iter().map(|(_, record)| record.time_ns).collect();
+        println!("\n{} [{}] {}", benchmark, source, sparkline(&times));
+
+        let mut previous: Option<&str> = None;
+        for (entry, record) in results {
+            let time = DateTime::parse_from_rfc3339(&entry.time)
+                .map(|time| {
+                    time.with_timezone(&Local)
+                        .format("%Y-%m-%d %H:%M")
+                        .to_string()
+                })
+                .unwrap_or_else(|_| entry.time.clone());
+            let commit = match &entry.commit {
+                Some(commit) => format!(
+                    "{}{}",
+                    &commit[..commit.len().min(7)],
+                    if entry.dirty { "*" } else { "" }
+                ),
+                None => "-".to_string(),
+            };
+
+            let mut answer = record.answer.clone().unwrap_or_default();
+            if let (Some(before), Some(now)) = (previous, &record.answer) {
+                if before != now {
+                    answer.push_str(" (changed)");
+                }
+            }
+            previous = record.answer.as_deref().or(previous);
+
+            let line = format!(
+                "  {}  {:<8}  {:>12?}  {}",
+                time,
+                commit,
+                Duration::from_nanos(record.time_ns),
+                answer
+            );
+            println!("{}", line.trim_end());
+        }
+    }
+
+    Ok(())
+}
+
+/// Draws `values` with a bar each, from the lowest to the highest.
+fn sparkline(values: &[u64]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    let min = values.iter().copied().min().unwrap_or_default();
+    let max = values.iter().copied().max().unwrap_or_default();
+
+    values
+        .iter()
+        .map(|&value| {
+            let scaled = (value - min) as f64 / (max - min).max(1) as f64;
+            BARS[(scaled * (BARS.len() - 1) as f64).round() as usize]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Recorded by the runner of the 2018 example with `--record`, missing the input of day 2
+    /// and with an invalid one for day 3.
+    const RUN: &str = r#"{"year":2018,"day":1,"part":1,"name":null,"generator":"parse_input_day1","best_ns":50114,"mean_ns":50114,"runs":1}
+{"year":2018,"day":1,"part":1,"name":null,"answer":"516","generator_ns":50114,"best_ns":3720,"mean_ns":3720,"runs":1,"both":false}
+{"year":2018,"day":1,"part":2,"name":null,"answer":"71892","generator_ns":50114,"best_ns":10999980,"mean_ns":10999980,"runs":1,"both":false}
+{"year":2018,"day":1,"part":2,"name":"Fnv","answer":"71892","generator_ns":50114,"best_ns":5545552,"mean_ns":5545552,"runs":1,"both":false}
+{"year":2018,"day":2,"part":1,"name":null,"error":"loading the input: missing input input/2018/day2.txt, download it with `cargo aoc input -d 2 -y 2018`"}
+{"year":2018,"day":3,"part":1,"name":null,"error":"generating: @ not found"}
+{"year":2018,"day":3,"part":2,"name":null,"error":"generating: @ not found"}
+"#;
+
+    #[test]
+    fn parse_recorded_run() {
+        let records = parse_run(RUN).unwrap();
+        let records: Vec<_> = records
+            .iter()
+            .map(|r| {
+                let DayPart { day, part, name } = &r.day_part;
+                let answer = r.answer.as_deref();
+                (day.0, part.0, name.as_deref(), r.kind, answer, r.time_ns)
+            })
+            .collect();
+
+        assert_eq!(
+            records,
+            [
+                (1, 1, None, Kind::Generator, None, 50114),
+                (1, 1, None, Kind::Solver, Some("516"), 3720),
+                (1, 2, None, Kind::Solver, Some("71892"), 10999980),
+                (1, 2, Some("Fnv"), Kind::Solver, Some("71892"), 5545552),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_run_fails_on_invalid_lines() {
+        assert!(parse_run("").unwrap().is_empty());
+        assert!(parse_run("Day 1 - Part 1 : 516").is_err());
+    }
+
+    #[test]
+    fn sparklines() {
+        assert_eq!(sparkline(&[]), "");
+        assert_eq!(sparkline(&[42]), "▁");
+        assert_eq!(sparkline(&[7, 7, 7]), "▁▁▁");
+        assert_eq!(sparkline(&[0, 1, 2, 3, 4, 5, 6, 7]), "▁▂▃▄▅▆▇█");
+        assert_eq!(sparkline(&[100, 300, 200]), "▁█▅");
+    }
+}
@@ -1,15 +1,25 @@
 mod app;
 mod args;
+mod baseline;
+mod bench;
 mod credentials;
 mod date;
+mod history;
 mod project;
+mod readme;
+mod status;
 mod errors;
 
 use aoc_runner_internal::{Day, Part};
-use app::{execute_bench, execute_credentials, execute_default, execute_input};
+use app::{
+    execute_bench, execute_credentials, execute_default, execute_history, execute_input,
+    execute_profile, execute_readme, execute_status,
+};
 
 use crate::args::args_without_aoc;
 use clap::Parser;
+use std::path::PathBuf;
+use std::time::Duration;
 
 #[derive(Parser, Debug)]
 #[clap(
@@ -29,6 +39,18 @@ pub struct Cli {
     /// Use an alternate input file.
     #[clap(short, long)]
     input: Option<String>,
+    /// Overrides a param of the solutions, e.g. `--param width=11`.
+    #[clap(long = "param", value_name = "KEY=VALUE")]
+    params: Vec<String>,
+    /// Runs parts not sharing a generator on N threads (0 for one per CPU).
+    #[clap(short, long, value_name = "N")]
+    jobs: Option<usize>,
+    /// Runs one part at a time, for accurate timings (overrides `--jobs`).
+    #[clap(long)]
+    sequential: bool,
+    /// Counts the allocations of each generator & solver.
+    #[clap(long)]
+    alloc: bool,
     /// Add debug info for profiling tools.
     #[clap(long)]
     profile: bool,
@@ -46,6 +68,12 @@ enum SubCommands {
     Bench(Bench),
     Credentials(Credentials),
     Input(Input),
+    Profile(Profile),
+    History(History),
+    Readme(Readme),
+    /// Shows the stars & personal stats of the year on the site, next to the local solutions
+    /// and inputs
+    Status,
 }
 
 /// Runs the benchmark for the last day (or a given day)
@@ -55,6 +83,20 @@ pub struct Bench {
     #[clap(short, long)]
     day: Option<Day>,
 
+    /// Benchmarks every implemented day, with a report of their end-to-end time over the year.
+    #[clap(long, conflicts_with_all = ["day", "input"])]
+    all: bool,
+
+    /// With `--all`, flags the days taking longer, e.g. `100ms`.
+    /// Defaults to `package.metadata.aoc.day-budget` in Cargo.toml.
+    #[clap(long, value_name = "DURATION", requires = "all", value_parser = bench::parse_duration)]
+    day_budget: Option<Duration>,
+
+    /// With `--all`, flags a year taking longer, e.g. `1s`.
+    /// Defaults to `package.metadata.aoc.year-budget` in Cargo.toml.
+    #[clap(long, value_name = "DURATION", requires = "all", value_parser = bench::parse_duration)]
+    year_budget: Option<Duration>,
+
     /// Specifies the part. Defaults to both parts.
     #[clap(short, long)]
     part: Option<Part>,
@@ -67,15 +109,101 @@ pub struct Bench {
     #[clap(short, long)]
     open: bool,
 
+    /// Harness measuring the benchmarks. Defaults to `package.metadata.aoc.bench-backend`
+    /// in Cargo.toml, or Criterion.
+    #[clap(short, long, value_enum)]
+    backend: Option<bench::Backend>,
+
+    /// Saves the estimates as a baseline, for later runs to compare with.
+    #[clap(long, value_name = "NAME")]
+    save_baseline: Option<String>,
+
+    /// Compares the estimates with a saved baseline.
+    #[clap(long, value_name = "NAME")]
+    baseline: Option<String>,
+
+    /// Fails if a benchmark is slower than the baseline by more than this, e.g. `10%`.
+    #[clap(long, value_name = "PERCENT", requires = "baseline", value_parser = baseline::parse_percent)]
+    max_regression: Option<f64>,
+
     /// Also benchmark generator functions.
     #[clap(short, long)]
     generator: bool,
 
+    /// Also counts the allocations of a run of each benchmark.
+    #[clap(long)]
+    alloc: bool,
+
     /// Add debug info for profiling tools.
     #[clap(long)]
     profile: bool,
 }
 
+/// Profiles the last day (or a given day & part), writing a flamegraph in `target/aoc/profile`
+#[derive(Parser, Debug)]
+pub struct Profile {
+    /// Specifies the day. Defaults to last implemented.
+    #[clap(short, long)]
+    day: Option<Day>,
+
+    /// Specifies the part. Defaults to the last implemented part of the day.
+    #[clap(short, long)]
+    part: Option<Part>,
+
+    /// Profiles the solution with this name, instead of the default one.
+    #[clap(short, long)]
+    name: Option<String>,
+
+    /// Use an alternate input file.
+    #[clap(short, long)]
+    input: Option<String>,
+
+    /// Overrides a param of the solution, e.g. `--param width=11`.
+    #[clap(long = "param", value_name = "KEY=VALUE")]
+    params: Vec<String>,
+
+    /// Runs the solution (generator included) in a loop for this long.
+    #[clap(long, value_name = "DURATION", default_value = "5s", value_parser = bench::parse_duration)]
+    duration: Duration,
+
+    /// Samples per second.
+    #[clap(long, default_value_t = 1000)]
+    frequency: u32,
+
+    /// Number of functions printed, by the share of samples spent in them.
+    #[clap(long, default_value_t = 20)]
+    top: usize,
+}
+
+/// Shows how the times & answers of a day (or every day) changed over the runs
+#[derive(Parser, Debug)]
+pub struct History {
+    /// Specifies the day. Defaults to every day.
+    #[clap(short, long)]
+    day: Option<Day>,
+
+    /// Specifies the part. Defaults to both parts.
+    #[clap(short, long)]
+    part: Option<Part>,
+
+    /// Only shows the last N results of each solution.
+    #[clap(short, long, value_name = "N")]
+    last: Option<usize>,
+}
+
+/// Updates the progress table of the README, and the badge of the stars
+#[derive(Parser, Debug)]
+pub struct Readme {
+    /// The README whose section between `<!-- cargo-aoc readme start -->` and
+    /// `<!-- cargo-aoc readme end -->` is rewritten (appended if missing).
+    #[clap(long, default_value = "README.md")]
+    readme: PathBuf,
+
+    /// Where to write the badge of the stars.
+    #[clap(long, default_value = "stars.svg")]
+    badge: PathBuf,
+}
+
 /// Sets the session cookie
 #[derive(Parser, Debug)]
 pub struct Credentials {
@@ -116,6 +244,10 @@ fn main() {
             Ok(())
         }
         SubCommands::Input(arg) => execute_input(&arg),
+        SubCommands::Profile(arg) => execute_profile(&arg),
+        SubCommands::History(arg) => execute_history(&arg),
+        SubCommands::Readme(arg) => execute_readme(&arg),
+        SubCommands::Status => execute_status(),
     }
     .unwrap()
 }
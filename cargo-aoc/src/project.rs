@@ -1,7 +1,10 @@
+use crate::bench::{self, Backend};
 use aoc_runner_internal::DayParts;
+use clap::ValueEnum;
 use std::error;
 use std::fs;
 use std::process;
+use std::time::Duration;
 use crate::errors::CouldNotLoadDayParts;
 
 #[derive(Clone, Debug)]
@@ -9,6 +12,11 @@ pub struct ProjectManager {
     pub name: String,
     pub slug: String,
     pub lib_path: Option<String>,
+    /// `package.metadata.aoc.bench-backend`, the default of `cargo aoc bench --backend`.
+    pub bench_backend: Option<Backend>,
+    /// `package.metadata.aoc.day-budget` & `year-budget`, the defaults of `cargo aoc bench --all`.
+    pub day_budget: Option<Duration>,
+    pub year_budget: Option<Duration>,
 }
 
 impl ProjectManager {
@@ -32,10 +40,40 @@ impl ProjectManager {
             .and_then(|lib_path| lib_path.as_str())
             .map(String::from);
 
+        let metadata = |key: &str| {
+            cargo
+                .get("package")
+                .and_then(|package| package.get("metadata"))
+                .and_then(|metadata| metadata.get("aoc"))
+                .and_then(|aoc| aoc.get(key))
+                .map(|value| {
+                    value
+                        .as_str()
+                        .ok_or_else(|| format!("invalid package.metadata.aoc.{} in Cargo.toml", key))
+                })
+                .transpose()
+        };
+
+        let bench_backend = metadata("bench-backend")?
+            .map(|backend| {
+                Backend::from_str(backend, true)
+                    .map_err(|e| format!("invalid package.metadata.aoc.bench-backend: {}", e))
+            })
+            .transpose()?;
+        let day_budget = metadata("day-budget")?
+            .map(bench::parse_duration)
+            .transpose()?;
+        let year_budget = metadata("year-budget")?
+            .map(bench::parse_duration)
+            .transpose()?;
+
         Ok(ProjectManager {
             name: crate_name,
             slug: crate_slug,
             lib_path,
+            bench_backend,
+            day_budget,
+            year_budget,
         })
     }
 
@@ -0,0 +1,249 @@
+use crate::date::AOCDate;
+use std::collections::BTreeMap;
+use std::error;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+/// Marks the section of the README rewritten by `cargo aoc readme`.
+const START: &str = "<!-- cargo-aoc readme start -->";
+const END: &str = "<!-- cargo-aoc readme end -->";
+
+/// The titles of the puzzles already fetched, by year & day.
+const TITLES: &str = "target/aoc/titles.json";
+
+/// A day of the progress table.
+pub struct Row {
+    pub date: AOCDate,
+    pub title: String,
+    pub stars: usize,
+    /// The files defining the solutions, relative to the project.
+    pub files: Vec<String>,
+    /// The time of the fastest solution of each part.
+    pub times: [Option<Duration>; 2],
+}
+
+/// Titles of the puzzles, cached in `target/aoc/titles.json`.
+#[derive(Default)]
+pub struct Titles(BTreeMap<u32, BTreeMap<u32, String>>);
+
+impl Titles {
+    pub fn load() -> Result<Titles, Box<dyn error::Error>> {
+        match fs::read_to_string(TITLES) {
+            Ok(content) => Ok(Titles(serde_json::from_str(&content)?)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Titles::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn error::Error>> {
+        fs::create_dir_all(Path::new(TITLES).parent().unwrap())?;
+        fs::write(TITLES, serde_json::to_string_pretty(&self.0)?)?;
+
+        Ok(())
+    }
+
+    /// The title of the puzzle, fetched with `fetch` if it wasn't yet.
+    pub fn get(
+        &mut self,
+        date: AOCDate,
+        fetch: impl FnOnce(AOCDate) -> Result<String, Box<dyn error::Error>>,
+    ) -> Result<String, Box<dyn error::Error>> {
+        let year = self.0.entry(date.year as u32).or_default();
+
+        if let Some(title) = year.get(&date.day) {
+            return Ok(title.clone());
+        }
+
+        let title = fetch(date)?;
+        year.insert(date.day, title.clone());
+        Ok(title)
+    }
+}
+
+/// Finds the title in the page of a puzzle : `<h2>--- Day 1: Chronal Calibration ---</h2>`.
+pub fn parse_title(html: &str) -> Option<String> {
+    let start = html.find("<h2>--- Day ")?;
+    let heading = &html[start..];
+    let heading = &heading[..heading.find("</h2>")?];
+    let (_, title) = heading.split_once(": ")?;
+
+    Some(
+        title
+            .trim_end_matches(" ---")
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&#39;", "'")
+            .replace("&amp;", "&"),
+    )
+}
+
+/// Renders the section of the README, with the table and the badge at `badge`.
+pub fn render(rows: &[Row], badge: &str) -> String {
+    let mut section = format!(
+        "{}\n![stars]({})\n\n| Day | Puzzle | Stars | Solution | Part 1 | Part 2 |\n\
+         | ---: | --- | :---: | --- | ---: | ---: |\n",
+        START, badge
+    );
+
+    for row in rows {
+        let files: Vec<_> = row
+            .files
+            .iter()
+            .map(|file| {
+                let name = Path::new(file)
+                    .file_name()
+                    .map_or(file.clone(), |name| name.to_string_lossy().to_string());
+                format!("[{}]({})", name, file)
+            })
+            .collect();
+        let time = |time: Option<Duration>| match time {
+            Some(time) if time < Duration::from_micros(1) => format!("{:?}", time),
+            Some(time) => format!("{:.2?}", time),
+            None => String::new(),
+        };
+
+        section.push_str(&format!(
+            "| {} | [{}]({}) | {} | {} | {} | {} |\n",
+            row.date.day,
+            row.title.replace('|', "\\|"),
+            row.date.puzzle_url(),
+            "⭐".repeat(row.stars),
+            files.join(", "),
+            time(row.times[0]),
+            time(row.times[1]),
+        ));
+    }
+
+    section.push_str(END);
+    section
+}
+
+/// Replaces the section between the markers of `readme` (created if missing), or appends it.
+///
+/// Returns whether it was appended.
+pub fn update(readme: &Path, section: &str) -> Result<bool, Box<dyn error::Error>> {
+    let content = match fs::read_to_string(readme) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
+    };
+
+    let (content, appended) =
+        replace_section(&content, section).map_err(|e| format!("{} in {}", e, readme.display()))?;
+
+    fs::write(readme, content)?;
+    Ok(appended)
+}
+
+/// Replaces the section between the markers of `content`, or appends it if there's none.
+///
+/// Fails if only one of the markers is found, rather than rewriting the wrong part of the README.
+fn replace_section(content: &str, section: &str) -> Result<(String, bool), String> {
+    let start = content.find(START);
+    let end = match start {
+        Some(start) => content[start..].find(END).map(|end| start + end),
+        None => content.find(END),
+    };
+
+    match (start, end) {
+        (Some(start), Some(end)) => Ok((
+            format!(
+                "{}{}{}",
+                &content[..start],
+                section,
+                &content[end + END.len()..]
+            ),
+            false,
+        )),
+        (None, None) if content.is_empty() => Ok((format!("{}\n", section), true)),
+        (None, None) => Ok((format!("{}\n\n{}\n", content.trim_end(), section), true)),
+        (Some(_), None) => Err(format!("`{}` found without `{}`", START, END)),
+        (None, Some(_)) => Err(format!("`{}` found without `{}`", END, START)),
+    }
+}
+
+/// A badge of the stars, in the style of shields.io.
+pub fn badge(stars: usize, total: usize) -> String {
+    let label = "stars";
+    let value = format!("{}/{}", stars, total);
+
+    // Verdana 11px is about 7px wide per character
+    let label_width = 7 * label.len() + 12;
+    let value_width = 7 * value.len() + 12;
+
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="20" role="img" aria-label="{label}: {value}">
+  <title>{label}: {value}</title>
+  <rect width="{label_width}" height="20" rx="3" fill="#555"/>
+  <rect x="{label_width}" width="{value_width}" height="20" rx="3" fill="#e3b341"/>
+  <rect x="{label_width}" width="4" height="20" fill="#e3b341"/>
+  <g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11">
+    <text x="{label_x}" y="14">{label}</text>
+    <text x="{value_x}" y="14" fill="#333">{value}</text>
+  </g>
+</svg>
+"##,
+        width = label_width + value_width,
+        label = label,
+        value = value,
+        label_width = label_width,
+        value_width = value_width,
+        label_x = label_width / 2,
+        value_x = label_width + value_width / 2,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECTION: &str =
+        "<!-- cargo-aoc readme start -->\nnew table\n<!-- cargo-aoc readme end -->";
+
+    #[test]
+    fn parse_title_of_puzzle() {
+        let html = r#"<main>
+<article class="day-desc"><h2>--- Day 12: Hot Springs &amp; &quot;Hot&quot; &lt;Springs&gt; ---</h2><p>You finally reach the hot springs!</p></article>
+</main>"#;
+
+        assert_eq!(
+            parse_title(html).as_deref(),
+            Some("Hot Springs & \"Hot\" <Springs>")
+        );
+        assert_eq!(parse_title("<main><h2>Puzzle</h2></main>"), None);
+    }
+
+    #[test]
+    fn replace_section_between_markers() {
+        let content = "# AoC\n\n<!-- cargo-aoc readme start -->\nold table\n<!-- cargo-aoc readme end -->\n\nNotes\n";
+
+        assert_eq!(
+            replace_section(content, SECTION),
+            Ok((format!("# AoC\n\n{}\n\nNotes\n", SECTION), false))
+        );
+    }
+
+    #[test]
+    fn replace_section_appends_without_markers() {
+        assert_eq!(
+            replace_section("# AoC\n\nNotes\n\n", SECTION),
+            Ok((format!("# AoC\n\nNotes\n\n{}\n", SECTION), true))
+        );
+        assert_eq!(
+            replace_section("", SECTION),
+            Ok((format!("{}\n", SECTION), true))
+        );
+    }
+
+    #[test]
+    fn replace_section_fails_with_a_single_marker() {
+        let end_only = "# AoC\n\nold table\n<!-- cargo-aoc readme end -->\n";
+        let start_only = "# AoC\n\n<!-- cargo-aoc readme start -->\nold table\n";
+
+        assert!(replace_section(end_only, SECTION).is_err());
+        assert!(replace_section(start_only, SECTION).is_err());
+    }
+}
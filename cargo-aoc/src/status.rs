@@ -0,0 +1,296 @@
+use crate::date::{days, AOCDate};
+use aoc_runner_internal::{DayParts, Part};
+use std::collections::BTreeMap;
+use std::error;
+use std::path::Path;
+
+/// What the site knows of a day : its stars, from the calendar, and when & how well each part
+/// was solved, from the personal stats.
+#[derive(Debug, Default)]
+pub struct SiteDay {
+    pub stars: usize,
+    pub parts: [Option<Completion>; 2],
+}
+
+/// The time from the release of the puzzle to its answer (e.g. `00:23:41` or `>24h`), and its rank.
+#[derive(Debug)]
+pub struct Completion {
+    pub time: String,
+    pub rank: Option<u32>,
+}
+
+/// Reads the stars of each day in the calendar, labelled like `aria-label="Day 5, two stars"`.
+///
+/// Fails if no day is found, e.g. if the site changed.
+pub fn parse_calendar(
+    html: &str,
+    site: &mut BTreeMap<u32, SiteDay>,
+) -> Result<(), Box<dyn error::Error>> {
+    const LABEL: &str = "aria-label=\"Day ";
+    let mut found = false;
+
+    for (start, _) in html.match_indices(LABEL) {
+        let label = html[start + LABEL.len()..]
+            .split('"')
+            .next()
+            .unwrap_or_default();
+        let (day, stars) = label.split_once(", ").unwrap_or((label, ""));
+
+        let Ok(day) = day.trim().parse() else {
+            continue;
+        };
+        let stars = match stars {
+            "one star" => 1,
+            "two stars" => 2,
+            _ => 0,
+        };
+
+        site.entry(day).or_default().stars = stars;
+        found = true;
+    }
+
+    if !found {
+        return Err("no day found in the calendar of the site".into());
+    }
+    Ok(())
+}
+
+/// Reads the table of the personal stats, whose rows are like
+/// ```text
+/// Day       Time   Rank  Score       Time   Rank  Score
+///   8   00:23:41   1155      0   00:31:37   1060      0
+///   3   01:02:03   4521      0          -      -      -
+/// ```
+/// Years without a global leaderboard only have the times.
+///
+/// Fails if the table or its rows aren't found, e.g. if the site changed.
+pub fn parse_stats(
+    html: &str,
+    site: &mut BTreeMap<u32, SiteDay>,
+) -> Result<(), Box<dyn error::Error>> {
+    let Some(start) = html.find("<pre") else {
+        return Err("no table found in the personal stats of the site".into());
+    };
+    let mut found = false;
+    let pre = &html[start..];
+    let pre = &pre[..pre.find("</pre>").unwrap_or(pre.len())];
+
+    for line in strip_tags(pre).lines() {
+        let mut columns = line.split_whitespace();
+        let Some(Ok(day)) = columns.next().map(str::parse::<u32>) else {
+            continue;
+        };
+
+        let columns: Vec<_> = columns.collect();
+        let day = site.entry(day).or_default();
+        found = true;
+
+        for (part, columns) in columns
+            .chunks(columns.len().div_ceil(2).max(1))
+            .enumerate()
+            .take(2)
+        {
+            if columns[0] == "-" {
+                continue;
+            }
+
+            day.parts[part] = Some(Completion {
+                time: columns[0].to_string(),
+                rank: columns.get(1).and_then(|rank| rank.parse().ok()),
+            });
+        }
+    }
+
+    if !found {
+        return Err("no day found in the personal stats of the site".into());
+    }
+    Ok(())
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.replace("&gt;", ">")
+        .replace("&lt;", "<")
+        .replace("&amp;", "&")
+}
+
+/// Prints each day solved on the site or locally, along with what's missing on either side.
+pub fn print(year: u32, site: &BTreeMap<u32, SiteDay>, day_parts: &DayParts) {
+    let last_day = days(year);
+    let mut mismatches = 0;
+    let (mut site_stars, mut local_stars) = (0, 0);
+
+    println!("AOC {}", year);
+    println!(
+        "{:>3}  {:<4}  {:<5}  {:<5}  {:>10} {:>7}  {:>10} {:>7}",
+        "Day", "Site", "Local", "Input", "Part 1", "Rank", "Part 2", "Rank"
+    );
+
+    for day in 1..=last_day {
+        let on_site = site.get(&day);
+        let stars = on_site.map_or(0, |d| d.stars);
+        let implemented = |part: u8| {
+            day_parts
+                .iter()
+                .any(|dp| u32::from(dp.day.0) == day && dp.part == Part(part))
+        };
+        let local = [implemented(1), implemented(2)];
+        let date = AOCDate {
+            day,
+            year: year as i32,
+        };
+        let input = Path::new(&date.filename()).exists();
+
+        if stars == 0 && !local.contains(&true) && !input {
+            continue;
+        }
+
+        let mut notes = Vec::new();
+        for (part, &implemented) in local.iter().enumerate() {
+            // The last star is given for all the others, without a puzzle
+            if day == last_day && part == 1 {
+                continue;
+            }
+
+            match (stars > part, implemented) {
+                (true, false) => notes.push(format!("part {} not implemented", part + 1)),
+                (false, true) => notes.push(format!("part {} not solved on the site", part + 1)),
+                _ => {}
+            }
+        }
+        if !input && (stars > 0 || local.contains(&true)) {
+            notes.push("input not downloaded".to_string());
+        }
+
+        let completion = |part: usize| match on_site.and_then(|d| d.parts[part].as_ref()) {
+            Some(Completion { time, rank }) => format!(
+                "{:>10} {:>7}",
+                time,
+                rank.map_or("-".to_string(), |r| r.to_string())
+            ),
+            None => format!("{:>10} {:>7}", "-", "-"),
+        };
+
+        let line = format!(
+            "{:>3}  {:<4}  {:<5}  {:<5}  {}  {}  {}",
+            day,
+            "*".repeat(stars),
+            "*".repeat(local.iter().filter(|&&l| l).count()),
+            if input { "yes" } else { "no" },
+            completion(0),
+            completion(1),
+            notes.join(", ")
+        );
+        println!("{}", line.trim_end());
+
+        mismatches += notes.len();
+        site_stars += stars;
+        local_stars += local.iter().filter(|&&l| l).count();
+    }
+
+    println!(
+        "\n{} stars on the site, {} parts implemented, {} mismatch(es)",
+        site_stars, local_stars, mismatches
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CALENDAR: &str = r#"<main>
+<pre class="calendar calendar-perfect"><a aria-label="Day 1, two stars" href="/2023/day/1" class="calendar-day1 calendar-verycomplete">  <span class="calendar-day"> 1</span> <span class="calendar-mark-complete">*</span><span class="calendar-mark-verycomplete">*</span></a>
+<a aria-label="Day 2, one star" href="/2023/day/2" class="calendar-day2 calendar-complete">  <span class="calendar-day"> 2</span> <span class="calendar-mark-complete">*</span><span class="calendar-mark-verycomplete">*</span></a>
+<a aria-label="Day 3" href="/2023/day/3" class="calendar-day3">  <span class="calendar-day"> 3</span> <span class="calendar-mark-complete">*</span><span class="calendar-mark-verycomplete">*</span></a>
+<span aria-hidden="true" class="calendar-day4">                           <span class="calendar-day"> 4</span></span>
+</pre>
+</main>"#;
+
+    const STATS: &str = r#"<main>
+<article><p>These are your personal leaderboard statistics.</p>
+<pre>      <span class="leaderboard-daydesc-first">--------Part 1--------</span>   <span class="leaderboard-daydesc-both">--------Part 2--------</span>
+Day   <span class="leaderboard-daydesc-first">    Time   Rank  Score</span>   <span class="leaderboard-daydesc-both">    Time   Rank  Score</span>
+  2   01:02:03   4521      0          -      -      -
+  1   00:23:41   1155      0   &gt;24h  60123      0
+</pre>
+</article>
+</main>"#;
+
+    // Years without a global leaderboard only have the times
+    const STATS_WITHOUT_LEADERBOARD: &str = r#"<main>
+<article><p>These are your personal leaderboard statistics.</p>
+<pre>      <span class="leaderboard-daydesc-first">-Part 1-</span>   <span class="leaderboard-daydesc-both">-Part 2-</span>
+Day   <span class="leaderboard-daydesc-first">    Time</span>   <span class="leaderboard-daydesc-both">    Time</span>
+  2   00:41:07          -
+  1   00:12:09   00:15:30
+</pre>
+</article>
+</main>"#;
+
+    #[test]
+    fn parse_calendar_stars() {
+        let mut site = BTreeMap::new();
+        parse_calendar(CALENDAR, &mut site).unwrap();
+
+        let stars: Vec<_> = site.iter().map(|(day, d)| (*day, d.stars)).collect();
+        assert_eq!(stars, [(1, 2), (2, 1), (3, 0)]);
+    }
+
+    #[test]
+    fn parse_calendar_fails_without_days() {
+        let mut site = BTreeMap::new();
+
+        assert!(parse_calendar("<main><pre class=\"calendar\"></pre></main>", &mut site).is_err());
+    }
+
+    fn completion(
+        site: &BTreeMap<u32, SiteDay>,
+        day: u32,
+        part: usize,
+    ) -> Option<(&str, Option<u32>)> {
+        site[&day].parts[part]
+            .as_ref()
+            .map(|c| (c.time.as_str(), c.rank))
+    }
+
+    #[test]
+    fn parse_stats_with_leaderboard() {
+        let mut site = BTreeMap::new();
+        parse_stats(STATS, &mut site).unwrap();
+
+        assert_eq!(completion(&site, 1, 0), Some(("00:23:41", Some(1155))));
+        assert_eq!(completion(&site, 1, 1), Some((">24h", Some(60123))));
+        assert_eq!(completion(&site, 2, 0), Some(("01:02:03", Some(4521))));
+        assert_eq!(completion(&site, 2, 1), None);
+    }
+
+    #[test]
+    fn parse_stats_without_leaderboard() {
+        let mut site = BTreeMap::new();
+        parse_stats(STATS_WITHOUT_LEADERBOARD, &mut site).unwrap();
+
+        assert_eq!(completion(&site, 1, 0), Some(("00:12:09", None)));
+        assert_eq!(completion(&site, 1, 1), Some(("00:15:30", None)));
+        assert_eq!(completion(&site, 2, 0), Some(("00:41:07", None)));
+        assert_eq!(completion(&site, 2, 1), None);
+    }
+
+    #[test]
+    fn parse_stats_fails_without_table() {
+        let mut site = BTreeMap::new();
+
+        assert!(parse_stats("<main><article></article></main>", &mut site).is_err());
+        assert!(parse_stats("<main><pre>Day   Time</pre></main>", &mut site).is_err());
+    }
+}
@@ -7,9 +7,4 @@ edition = "2021"
 [dependencies]
 {CRATE_NAME} = { path = "../../.." }
 
-# For release
-aoc-runner = "0.3"
-# For dev
-# aoc-runner = { path = "../../../../aoc-runner" }
-
 {PROFILE}
\ No newline at end of file
@@ -0,0 +1,65 @@
+extern crate {CRATE_SLUG};
+
+use {CRATE_SLUG}::*;
+use {CRATE_SLUG}::__aoc_runner::alloc::{self, Counting};
+use {CRATE_SLUG}::__aoc_runner::ArcStr;
+use std::fmt::{Display, Write};
+use std::fs;
+use std::hint;
+
+#[global_allocator]
+static ALLOC: Counting = Counting;
+
+#[inline]
+fn black_box(t: &dyn Display) {
+    hint::black_box(t);
+}
+
+/// Counts the allocations of a run of each benchmark, and writes them in
+/// `target/alloc/allocations.jsonl`.
+#[derive(Default)]
+struct Harness {
+    allocations: String,
+}
+
+impl Harness {
+    /// Counts the allocations of `routine`, on an input prepared by `setup`.
+    fn bench<I>(&mut self, group: &str, name: &str, mut setup: impl FnMut() -> I, mut routine: impl FnMut(I)) {
+        let input = setup();
+        let ((), allocs) = alloc::measure(|| routine(input));
+
+        println!("{:<40} {}", format!("{}/{}", group, name), allocs);
+
+        let _ = writeln!(
+            self.allocations,
+            r#"{{"group":{:?},"function":{:?},"allocs":{},"alloc_bytes":{},"peak_bytes":{}}}"#,
+            group, name, allocs.count, allocs.bytes, allocs.peak
+        );
+    }
+}
+
+fn aoc_benchmark(harness: &mut Harness) {
+    {INPUTS}
+
+    {PARTS}
+}
+
+#[allow(unused_variables)]
+#[allow(dead_code)]
+fn input_benchmark(harness: &mut Harness) {
+    {INPUTS}
+
+    {GENS}
+}
+
+fn main() {
+    let mut harness = Harness::default();
+
+    for benchmark in [{BENCHMARKS}] {
+        benchmark(&mut harness);
+    }
+
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/target/alloc");
+    fs::create_dir_all(dir).expect("failed to create target/alloc");
+    fs::write(format!("{}/allocations.jsonl", dir), harness.allocations).expect("failed to write the allocations");
+}
@@ -0,0 +1,15 @@
+[package]
+name = "aoc-autobench"
+version = "0.3.0"
+authors = ["Grégory Obanos <gregory.obanos@gmail.com>"]
+edition = "2021"
+
+[dependencies]
+{CRATE_NAME} = { path = "../../.." }
+
+{PROFILE}
+
+[[bench]]
+name = "aoc_benchmark"
+harness = false
+{ALLOC_BENCH}
@@ -0,0 +1,103 @@
+extern crate {CRATE_SLUG};
+
+use {CRATE_SLUG}::*;
+use {CRATE_SLUG}::__aoc_runner::ArcStr;
+use std::fmt::{Display, Write};
+use std::fs;
+use std::hint;
+use std::time::{Duration, Instant};
+
+/// Time spent measuring each benchmark, after its warm up.
+const MEASURE: Duration = Duration::from_secs(3);
+
+/// Number of samples of each benchmark, unless its runs are too slow.
+const SAMPLES: u32 = 100;
+
+/// Maximum number of runs in a sample, whose inputs are prepared beforehand.
+const BATCH: u32 = 100;
+
+#[inline]
+fn black_box(t: &dyn Display) {
+    hint::black_box(t);
+}
+
+/// Measures the benchmarks, and writes their estimates in `target/builtin/estimates.jsonl`.
+#[derive(Default)]
+struct Harness {
+    estimates: String,
+}
+
+impl Harness {
+    /// Measures `routine` on inputs prepared by `setup`, outside of the measure.
+    fn bench<I>(&mut self, group: &str, name: &str, mut setup: impl FnMut() -> I, mut routine: impl FnMut(I)) {
+        // Warm up, and estimate the time of a single run
+        let mut warm_up = Duration::ZERO;
+        let mut runs = 0;
+
+        while runs == 0 || warm_up < MEASURE / 10 {
+            let input = setup();
+            let start_time = Instant::now();
+            routine(input);
+            warm_up += start_time.elapsed();
+            runs += 1;
+        }
+
+        let run = (warm_up / runs).max(Duration::from_nanos(1));
+        let samples = (MEASURE.as_nanos() / run.as_nanos()).clamp(10, u128::from(SAMPLES)) as u32;
+        let batch = (MEASURE.as_nanos() / (run.as_nanos() * u128::from(samples))).clamp(1, u128::from(BATCH)) as u32;
+
+        let mut times: Vec<f64> = (0..samples)
+            .map(|_| {
+                let inputs: Vec<_> = (0..batch).map(|_| setup()).collect();
+                let start_time = Instant::now();
+                inputs.into_iter().for_each(&mut routine);
+                start_time.elapsed().as_nanos() as f64 / f64::from(batch)
+            })
+            .collect();
+
+        times.sort_by(f64::total_cmp);
+        let median = times[times.len() / 2];
+        let mean = times.iter().sum::<f64>() / times.len() as f64;
+
+        println!(
+            "{:<40} median {:>12?}  mean {:>12?}  ({} samples of {} runs)",
+            format!("{}/{}", group, name),
+            Duration::from_nanos(median as u64),
+            Duration::from_nanos(mean as u64),
+            samples,
+            batch,
+        );
+
+        let _ = writeln!(
+            self.estimates,
+            r#"{{"group":{:?},"function":{:?},"median_ns":{},"mean_ns":{}}}"#,
+            group, name, median, mean
+        );
+    }
+}
+
+fn aoc_benchmark(harness: &mut Harness) {
+    {INPUTS}
+
+    {PARTS}
+}
+
+#[allow(unused_variables)]
+#[allow(dead_code)]
+fn input_benchmark(harness: &mut Harness) {
+    {INPUTS}
+
+    {GENS}
+}
+
+fn main() {
+    let mut harness = Harness::default();
+
+    for benchmark in [{BENCHMARKS}] {
+        benchmark(&mut harness);
+    }
+
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/target/builtin");
+    fs::create_dir_all(dir).expect("failed to create target/builtin");
+    fs::write(format!("{}/estimates.jsonl", dir), harness.estimates).expect("failed to write the estimates");
+}
@@ -0,0 +1,5 @@
+    {
+        let group = "End-to-end Day{DAY} - Part{PART}";
+
+        {IMPLS}
+    }
@@ -0,0 +1,6 @@
+        {
+            let input = input_day{DAY}.clone();
+            harness.bench(group, "{NAME}", || input.clone(), |input| {
+                Factory::{RUNNER_NAME}(input).unwrap().bench(black_box);
+            });
+        }
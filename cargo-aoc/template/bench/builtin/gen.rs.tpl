@@ -0,0 +1,5 @@
+    {
+        let group = "Generator Day{DAY} - Part{PART}";
+
+        {IMPLS}
+    }
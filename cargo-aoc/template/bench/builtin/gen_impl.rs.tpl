@@ -0,0 +1,6 @@
+        {
+            let input = input_day{DAY}.clone();
+            harness.bench(group, "{NAME}", || input.clone(), |input| {
+                hint::black_box(Factory::{RUNNER_NAME}(input).unwrap());
+            });
+        }
@@ -0,0 +1,5 @@
+        {
+            let runner = Factory::{RUNNER_NAME}(input_day{DAY}.clone())
+                .expect("failed to generate input for {NAME}");
+            harness.bench(group, "{NAME}", || runner.setup(), |input| runner.bench_batched(input, black_box));
+        }
@@ -0,0 +1,5 @@
+    {
+        let group = "Day{DAY} - Part{PART}";
+
+        {IMPLS}
+    }
@@ -7,11 +7,6 @@ edition = "2021"
 [dependencies]
 {CRATE_NAME} = { path = "../../.." }
 
-# For release
-aoc-runner = "0.3"
-# For dev
-# aoc-runner = { path = "../../../../aoc-runner" }
-
 [dev-dependencies]
 criterion = "0.5.1"
 
@@ -20,3 +15,4 @@ criterion = "0.5.1"
 [[bench]]
 name = "aoc_benchmark"
 harness = false
+{ALLOC_BENCH}
@@ -1,11 +1,10 @@
 #[macro_use]
 extern crate criterion;
 extern crate {CRATE_SLUG};
-extern crate aoc_runner;
 
 use {CRATE_SLUG}::*;
-use aoc_runner::ArcStr;
-use criterion::Criterion;
+use {CRATE_SLUG}::__aoc_runner::ArcStr;
+use criterion::{BatchSize, Criterion};
 use std::fmt::Display;
 
 #[inline]
@@ -0,0 +1,5 @@
+    let mut group = c.benchmark_group("End-to-end Day{DAY} - Part{PART}");
+
+    {IMPLS}
+
+    group.finish();
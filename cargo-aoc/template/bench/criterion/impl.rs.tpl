@@ -0,0 +1,6 @@
+
+    {
+        let runner = Factory::{RUNNER_NAME}(input_day{DAY}.clone())
+            .expect("failed to generate input for {NAME}");
+        group.bench_function("{NAME}", move |b| b.iter_batched(|| runner.setup(), |input| runner.bench_batched(input, black_box), BatchSize::SmallInput));
+    }
\ No newline at end of file
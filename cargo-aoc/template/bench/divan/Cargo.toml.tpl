@@ -0,0 +1,18 @@
+[package]
+name = "aoc-autobench"
+version = "0.3.0"
+authors = ["Grégory Obanos <gregory.obanos@gmail.com>"]
+edition = "2021"
+
+[dependencies]
+{CRATE_NAME} = { path = "../../.." }
+
+[dev-dependencies]
+divan = "0.1.21"
+
+{PROFILE}
+
+[[bench]]
+name = "aoc_benchmark"
+harness = false
+{ALLOC_BENCH}
@@ -0,0 +1,20 @@
+extern crate divan;
+extern crate {CRATE_SLUG};
+
+use {CRATE_SLUG}::*;
+use {CRATE_SLUG}::__aoc_runner::ArcStr;
+use divan::Bencher;
+use std::fmt::Display;
+
+#[inline]
+fn black_box(t: &dyn Display) {
+    divan::black_box(t);
+}
+
+fn main() {
+    divan::main();
+}
+
+{PARTS}
+
+{GENS}
@@ -0,0 +1,6 @@
+#[divan::bench_group(name = "End-to-end Day{DAY} - Part{PART}")]
+mod end_to_end_{PART_NAME} {
+    use super::*;
+
+    {IMPLS}
+}
@@ -0,0 +1,6 @@
+#[divan::bench_group(name = "Generator Day{DAY} - Part{PART}")]
+mod generator_{PART_NAME} {
+    use super::*;
+
+    {IMPLS}
+}
@@ -0,0 +1,5 @@
+    #[divan::bench(name = "{NAME}")]
+    fn {RUNNER_NAME}(bencher: Bencher) {
+        {INPUTS}
+        bencher.with_inputs(|| input_day{DAY}.clone()).bench_local_values(|input| Factory::{RUNNER_NAME}(input).unwrap());
+    }
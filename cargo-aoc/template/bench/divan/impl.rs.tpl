@@ -0,0 +1,7 @@
+    #[divan::bench(name = "{NAME}")]
+    fn {RUNNER_NAME}(bencher: Bencher) {
+        {INPUTS}
+        let runner = Factory::{RUNNER_NAME}(input_day{DAY})
+            .expect("failed to generate input for {NAME}");
+        bencher.with_inputs(|| runner.setup()).bench_local_values(|input| runner.bench_batched(input, black_box));
+    }
@@ -0,0 +1,6 @@
+#[divan::bench_group(name = "Day{DAY} - Part{PART}")]
+mod {PART_NAME} {
+    use super::*;
+
+    {IMPLS}
+}
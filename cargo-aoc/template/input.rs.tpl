@@ -1,2 +1 @@
-
-    let input_day{DAY} = ArcStr::from(include_str!("{PATH}"));
+    let input_day{DAY} = ArcStr::from(&*std::fs::read_to_string({PATH}).unwrap_or_else(|e| panic!("failed to read {}: {}", {PATH}, e)));
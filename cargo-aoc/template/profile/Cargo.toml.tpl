@@ -0,0 +1,13 @@
+[package]
+name = "aoc-autoprofile"
+version = "0.3.0"
+authors = ["Grégory Obanos <gregory.obanos@gmail.com>"]
+edition = "2021"
+
+[dependencies]
+{CRATE_NAME} = { path = "../../.." }
+pprof = { version = "0.15", features = ["flamegraph", "prost-codec"] }
+
+# Symbols & line numbers of the samples
+[profile.release]
+debug = true
@@ -0,0 +1,156 @@
+extern crate {CRATE_SLUG};
+extern crate pprof;
+
+use {CRATE_SLUG}::__aoc_runner::cli::{self, Args, Source};
+use {CRATE_SLUG}::__aoc_runner::{ArcStr, Inputs};
+use pprof::protos::Message;
+use pprof::Symbol;
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::hint;
+use std::io::{self, Read};
+use std::path::Path;
+use std::process;
+use std::time::{Duration, Instant};
+
+/// Runs the solution selected by the arguments in a loop, sampled by pprof, then writes a
+/// flamegraph in `{output}.svg`, a pprof profile in `{output}.pb`, and prints the top functions.
+fn main() {
+    if let Err(e) = profile() {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
+
+fn profile() -> Result<(), Box<dyn Error>> {
+    let mut duration = Duration::from_secs(5);
+    let mut frequency = 1000;
+    let mut top = 20;
+    let mut output = String::from("target/aoc/profile/profile");
+    let mut argv = Vec::new();
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("missing value for {}", arg));
+
+        match arg.as_str() {
+            "--seconds" => duration = Duration::from_secs_f64(value()?.parse()?),
+            "--frequency" => frequency = value()?.parse()?,
+            "--top" => top = value()?.parse()?,
+            "--output" => output = value()?,
+            _ => argv.push(arg),
+        }
+    }
+
+    let mut args = Args::parse_from(argv).map_err(|e| e.to_string())?;
+    args.year.get_or_insert({CRATE_SLUG}::YEAR);
+
+    // The default solution, unless a name is given
+    let solution = {CRATE_SLUG}::__aoc_runner::registry()
+        .into_iter()
+        .find(|s| args.matches(s) && (args.name.is_some() || s.name.is_none()))
+        .ok_or("No matching day & part found")?;
+    cli::check_params(&[solution], &args.params)?;
+
+    let input = match &args.input {
+        Some(Source::File(path)) => fs::read_to_string(path)?,
+        Some(Source::Stdin) => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            input
+        }
+        None => Inputs::new("input").read(solution.year, solution.day)?,
+    };
+    let input = ArcStr::from(&input);
+
+    println!("Profiling {} for {:?}...", solution, duration);
+
+    let guard = pprof::ProfilerGuardBuilder::default()
+        .frequency(frequency)
+        .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+        .build()?;
+
+    let start_time = Instant::now();
+    let mut runs = 0;
+    while runs == 0 || start_time.elapsed() < duration {
+        let runner = solution.runner_with(&solution.generate(input.clone())?, &args.params)?;
+        hint::black_box(runner.try_run()?);
+        runs += 1;
+    }
+    let elapsed = start_time.elapsed();
+
+    let report = guard.report().build()?;
+
+    if let Some(dir) = Path::new(&output).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    report.flamegraph(fs::File::create(format!("{}.svg", output))?)?;
+
+    let mut profile = Vec::new();
+    report.pprof()?.encode(&mut profile)?;
+    fs::write(format!("{}.pb", output), profile)?;
+
+    // Samples of each function : at the top of the stack, and anywhere in it
+    let mut functions: HashMap<String, (isize, isize)> = HashMap::new();
+    let mut samples = 0;
+
+    for (frames, count) in &report.data {
+        samples += count;
+        let mut seen = HashSet::new();
+
+        for (depth, symbol) in frames.frames.iter().flatten().enumerate() {
+            let name = function_name(symbol);
+            let (self_samples, total_samples) = functions.entry(name.clone()).or_default();
+
+            if depth == 0 {
+                *self_samples += count;
+            }
+            if seen.insert(name) {
+                *total_samples += count;
+            }
+        }
+    }
+
+    let mut functions: Vec<_> = functions.into_iter().collect();
+    functions.sort_by(|(a, (a_self, a_total)), (b, (b_self, b_total))| {
+        (b_self, b_total, a).cmp(&(a_self, a_total, b))
+    });
+
+    println!(
+        "{} runs in {:?} ({:?} per run), {} samples",
+        runs,
+        elapsed,
+        elapsed / runs,
+        samples
+    );
+    println!("\n  self   total  function");
+
+    let percent = |count: isize| 100.0 * count as f64 / samples.max(1) as f64;
+    for (name, (self_samples, total_samples)) in functions.iter().take(top) {
+        println!(
+            "{:>5.1}%  {:>5.1}%  {}",
+            percent(*self_samples),
+            percent(*total_samples),
+            name
+        );
+    }
+
+    println!("\nFlamegraph : {}.svg", output);
+    println!("Profile    : {}.pb (e.g. `go tool pprof -http=: {}.pb`)", output, output);
+
+    Ok(())
+}
+
+/// The demangled name of the function, without its hash.
+fn function_name(symbol: &Symbol) -> String {
+    let name = symbol.name();
+
+    match name.rsplit_once("::h") {
+        Some((path, hash)) if hash.len() == 16 && hash.chars().all(|c| c.is_ascii_hexdigit()) => {
+            path.to_string()
+        }
+        _ => name,
+    }
+}
@@ -1,14 +1,8 @@
 extern crate {CRATE_SLUG};
-extern crate aoc_runner;
 
-use {CRATE_SLUG}::*;
-use std::time::Instant;
-use aoc_runner::ArcStr;
+use {CRATE_SLUG}::__aoc_runner::{cli, Inputs};
+{ALLOC}
 
 fn main() {
-    println!("AOC {YEAR}");
-
-    {INPUT}
-
-    {BODY}
-}
\ No newline at end of file
+    cli::main({CRATE_SLUG}::YEAR, Inputs::new("input"))
+}
@@ -6,42 +6,156 @@ fn parse_input_day1(input: &str) -> Result<Vec<i32>, ParseIntError> {
     input.lines().map(|l| l.parse()).collect()
 }
 
+#[doc(hidden)]
+#[allow(unused_imports, non_snake_case)]
+mod __aoc_gen_day1_part1_parse_input_day1 {
+    use super::*;
+    use aoc_runner::kind::{Lend, LendsAs, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{ArcStr, Generated};
+
+    impl aoc_runner::kind::Generator for crate::aoc_factory::generators::Day1Part1 {
+        const NAME: Option<&'static str> = Some("parse_input_day1");
+        const ID: Option<&'static str> = Some(module_path!());
+
+        fn generate(input: ArcStr) -> Result<Generated, Box<dyn std::error::Error>> {
+            let output = parse_input_day1(std::borrow::Borrow::borrow(&input));
+            let output = (&output).aoc_kind().checked(output)?;
+
+            Ok(std::sync::Arc::new(output))
+        }
+
+        fn accepts(generated: &Generated) -> bool {
+            generated.is::<Result<Vec<i32>, ParseIntError>>()
+        }
+    }
+
+    impl<A: Takes, K> Lend<A, K> for crate::aoc_factory::generators::Day1Part1
+    where
+        for<'a> Result<Vec<i32>, ParseIntError>: LendsAs<A::Input<'a>, K>,
+    {
+        fn lend<R>(generated: &Generated, f: impl for<'a> FnOnce(&'a A::Input<'a>) -> R) -> R {
+            f(LendsAs::lent(
+                generated
+                    .downcast_ref::<Result<Vec<i32>, ParseIntError>>()
+                    .unwrap(),
+            ))
+        }
+    }
+
+    impl aoc_runner::kind::Generator for crate::aoc_factory::generators::Day1Part2 {
+        const NAME: Option<&'static str> = Some("parse_input_day1");
+        const ID: Option<&'static str> = Some(module_path!());
+
+        fn generate(input: ArcStr) -> Result<Generated, Box<dyn std::error::Error>> {
+            let output = parse_input_day1(std::borrow::Borrow::borrow(&input));
+            let output = (&output).aoc_kind().checked(output)?;
+
+            Ok(std::sync::Arc::new(output))
+        }
+
+        fn accepts(generated: &Generated) -> bool {
+            generated.is::<Result<Vec<i32>, ParseIntError>>()
+        }
+    }
+
+    impl<A: Takes, K> Lend<A, K> for crate::aoc_factory::generators::Day1Part2
+    where
+        for<'a> Result<Vec<i32>, ParseIntError>: LendsAs<A::Input<'a>, K>,
+    {
+        fn lend<R>(generated: &Generated, f: impl for<'a> FnOnce(&'a A::Input<'a>) -> R) -> R {
+            f(LendsAs::lent(
+                generated
+                    .downcast_ref::<Result<Vec<i32>, ParseIntError>>()
+                    .unwrap(),
+            ))
+        }
+    }
+}
+
 fn part1(freqs: &[i32]) -> i32 {
     freqs.iter().sum()
 }
 
+#[allow(unused_imports)]
 mod day1_part1 {
     use super::*;
-    use crate::{Day1Part1, Factory};
-    use aoc_runner::{ArcStr, Runner};
-    use std::borrow::Borrow;
-    use std::error::Error;
-    use std::fmt::Display;
-    use std::marker::PhantomData;
+    use crate::{Day1Part1, Factory, YEAR};
+    use aoc_runner::kind::{Generator, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{inventory, Answer, ArcStr, Generated, Location, Params, Runner, Solution};
+    const PARAMS: &[(&str, &str)] = &[];
+
     impl Day1Part1 for Factory {
-        fn day1_part1(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>> {
-            Ok(Box::new(RunnerStruct::try_gen(input)?))
+        fn day1_part1(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+            runner(
+                &<crate::aoc_factory::inputs::Day1Part1 as Generator>::generate(input)?,
+                Params::from(PARAMS),
+            )
+        }
+    }
+
+    inventory::submit! {
+        Solution::new(
+            YEAR,
+            1,
+            1,
+            None,
+            false,
+            <crate::aoc_factory::inputs::Day1Part1 as Generator>::NAME,
+            <crate::aoc_factory::inputs::Day1Part1 as Generator>::ID,
+            Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+            PARAMS,
+            <crate::aoc_factory::inputs::Day1Part1 as Generator>::generate,
+            runner,
+        )
+    }
+    struct __SolverInput;
+
+    impl Takes for __SolverInput {
+        type Input<'a> = [i32];
+    }
+    fn runner(
+        generated: &Generated,
+        params: Params,
+    ) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+        if !<crate::aoc_factory::inputs::Day1Part1 as Generator>::accepts(generated) {
+            return Err("unexpected generator output".into());
         }
+
+        Ok(Box::new(RunnerStruct {
+            input: generated.clone(),
+            params,
+        }))
     }
     pub struct RunnerStruct {
-        input: Vec<i32>,
-        output: PhantomData<i32>,
+        input: Generated,
+        #[allow(dead_code)]
+        params: Params,
     }
     impl Runner for RunnerStruct {
-        fn gen(input: ArcStr) -> Self {
-            Self::try_gen(input).expect("failed to generate input")
-        }
-        fn try_gen(input: ArcStr) -> Result<Self, Box<dyn Error>> {
-            Ok(RunnerStruct {
-                input: parse_input_day1(input.borrow())?,
-                output: PhantomData,
-            })
+        fn run(&self) -> Answer {
+            self.try_run().expect("failed to run")
         }
-        fn run(&self) -> Box<dyn Display> {
-            Box::new(part1(self.input.borrow()))
+        fn try_run(&self) -> Result<Answer, Box<dyn std::error::Error>> {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day1Part1, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part1(input);
+                    (&output).aoc_kind().answer(output)
+                },
+            )
         }
-        fn bench(&self, black_box: fn(&dyn Display)) {
-            black_box(&part1(self.input.borrow()))
+        fn bench(&self, black_box: fn(&dyn std::fmt::Display)) {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day1Part1, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part1(input);
+                    black_box((&output).aoc_kind().display(&output))
+                },
+            )
         }
     }
 }
@@ -64,38 +178,86 @@ fn part2(freqs: &[i32]) -> i32 {
     sum
 }
 
+#[allow(unused_imports)]
 mod day1_part2 {
     use super::*;
-    use crate::{Day1Part2, Factory};
-    use aoc_runner::{ArcStr, Runner};
-    use std::borrow::Borrow;
-    use std::error::Error;
-    use std::fmt::Display;
-    use std::marker::PhantomData;
+    use crate::{Day1Part2, Factory, YEAR};
+    use aoc_runner::kind::{Generator, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{inventory, Answer, ArcStr, Generated, Location, Params, Runner, Solution};
+    const PARAMS: &[(&str, &str)] = &[];
+
     impl Day1Part2 for Factory {
-        fn day1_part2(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>> {
-            Ok(Box::new(RunnerStruct::try_gen(input)?))
+        fn day1_part2(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+            runner(
+                &<crate::aoc_factory::inputs::Day1Part2 as Generator>::generate(input)?,
+                Params::from(PARAMS),
+            )
         }
     }
+
+    inventory::submit! {
+        Solution::new(
+            YEAR,
+            1,
+            2,
+            None,
+            false,
+            <crate::aoc_factory::inputs::Day1Part2 as Generator>::NAME,
+            <crate::aoc_factory::inputs::Day1Part2 as Generator>::ID,
+            Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+            PARAMS,
+            <crate::aoc_factory::inputs::Day1Part2 as Generator>::generate,
+            runner,
+        )
+    }
+    struct __SolverInput;
+
+    impl Takes for __SolverInput {
+        type Input<'a> = [i32];
+    }
+    fn runner(
+        generated: &Generated,
+        params: Params,
+    ) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+        if !<crate::aoc_factory::inputs::Day1Part2 as Generator>::accepts(generated) {
+            return Err("unexpected generator output".into());
+        }
+
+        Ok(Box::new(RunnerStruct {
+            input: generated.clone(),
+            params,
+        }))
+    }
     pub struct RunnerStruct {
-        input: Vec<i32>,
-        output: PhantomData<i32>,
+        input: Generated,
+        #[allow(dead_code)]
+        params: Params,
     }
     impl Runner for RunnerStruct {
-        fn gen(input: ArcStr) -> Self {
-            Self::try_gen(input).expect("failed to generate input")
-        }
-        fn try_gen(input: ArcStr) -> Result<Self, Box<dyn Error>> {
-            Ok(RunnerStruct {
-                input: parse_input_day1(input.borrow())?,
-                output: PhantomData,
-            })
+        fn run(&self) -> Answer {
+            self.try_run().expect("failed to run")
         }
-        fn run(&self) -> Box<dyn Display> {
-            Box::new(part2(self.input.borrow()))
+        fn try_run(&self) -> Result<Answer, Box<dyn std::error::Error>> {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day1Part2, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part2(input);
+                    (&output).aoc_kind().answer(output)
+                },
+            )
         }
-        fn bench(&self, black_box: fn(&dyn Display)) {
-            black_box(&part2(self.input.borrow()))
+        fn bench(&self, black_box: fn(&dyn std::fmt::Display)) {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day1Part2, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part2(input);
+                    black_box((&output).aoc_kind().display(&output))
+                },
+            )
         }
     }
 }
@@ -118,38 +280,86 @@ fn part2_fnv(freqs: &[i32]) -> i32 {
     sum
 }
 
+#[allow(unused_imports)]
 mod day1_part2_fnv {
     use super::*;
-    use crate::{Day1Part2FNV, Factory};
-    use aoc_runner::{ArcStr, Runner};
-    use std::borrow::Borrow;
-    use std::error::Error;
-    use std::fmt::Display;
-    use std::marker::PhantomData;
+    use crate::{Day1Part2FNV, Factory, YEAR};
+    use aoc_runner::kind::{Generator, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{inventory, Answer, ArcStr, Generated, Location, Params, Runner, Solution};
+    const PARAMS: &[(&str, &str)] = &[];
+
     impl Day1Part2FNV for Factory {
-        fn day1_part2_fnv(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>> {
-            Ok(Box::new(RunnerStruct::try_gen(input)?))
+        fn day1_part2_fnv(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+            runner(
+                &<crate::aoc_factory::inputs::Day1Part2FNV as Generator>::generate(input)?,
+                Params::from(PARAMS),
+            )
+        }
+    }
+
+    inventory::submit! {
+        Solution::new(
+            YEAR,
+            1,
+            2,
+            Some("Fnv"),
+            false,
+            <crate::aoc_factory::inputs::Day1Part2FNV as Generator>::NAME,
+            <crate::aoc_factory::inputs::Day1Part2FNV as Generator>::ID,
+            Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+            PARAMS,
+            <crate::aoc_factory::inputs::Day1Part2FNV as Generator>::generate,
+            runner,
+        )
+    }
+    struct __SolverInput;
+
+    impl Takes for __SolverInput {
+        type Input<'a> = [i32];
+    }
+    fn runner(
+        generated: &Generated,
+        params: Params,
+    ) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+        if !<crate::aoc_factory::inputs::Day1Part2FNV as Generator>::accepts(generated) {
+            return Err("unexpected generator output".into());
         }
+
+        Ok(Box::new(RunnerStruct {
+            input: generated.clone(),
+            params,
+        }))
     }
     pub struct RunnerStruct {
-        input: Vec<i32>,
-        output: PhantomData<i32>,
+        input: Generated,
+        #[allow(dead_code)]
+        params: Params,
     }
     impl Runner for RunnerStruct {
-        fn gen(input: ArcStr) -> Self {
-            Self::try_gen(input).expect("failed to generate input")
-        }
-        fn try_gen(input: ArcStr) -> Result<Self, Box<dyn Error>> {
-            Ok(RunnerStruct {
-                input: parse_input_day1(input.borrow())?,
-                output: PhantomData,
-            })
+        fn run(&self) -> Answer {
+            self.try_run().expect("failed to run")
         }
-        fn run(&self) -> Box<dyn Display> {
-            Box::new(part2_fnv(self.input.borrow()))
+        fn try_run(&self) -> Result<Answer, Box<dyn std::error::Error>> {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day1Part2FNV, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part2_fnv(input);
+                    (&output).aoc_kind().answer(output)
+                },
+            )
         }
-        fn bench(&self, black_box: fn(&dyn Display)) {
-            black_box(&part2_fnv(self.input.borrow()))
+        fn bench(&self, black_box: fn(&dyn std::fmt::Display)) {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day1Part2FNV, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part2_fnv(input);
+                    black_box((&output).aoc_kind().display(&output))
+                },
+            )
         }
     }
 }
@@ -1,9 +1,83 @@
 use fnv::FnvHashMap;
 use std::collections::HashMap;
 
-fn part1(input: &str) -> u32 {
-    let (nb_double, nb_triple) = input
-        .lines()
+fn parse(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+#[doc(hidden)]
+#[allow(unused_imports, non_snake_case)]
+mod __aoc_gen_day2_part1_parse {
+    use super::*;
+    use aoc_runner::kind::{Lend, LendsAs, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{ArcStr, Generated};
+
+    pub(super) struct Family;
+
+    impl aoc_runner::kind::Family for Family {
+        type Output<'a> = Vec<&'a str>;
+    }
+
+    impl aoc_runner::kind::Generator for crate::aoc_factory::generators::Day2Part1 {
+        const NAME: Option<&'static str> = Some("parse");
+        const ID: Option<&'static str> = Some(module_path!());
+
+        fn generate(input: ArcStr) -> Result<Generated, Box<dyn std::error::Error>> {
+            let output = aoc_runner::kind::Borrowed::<Family>::new(input, parse);
+            output.with(|output| output.aoc_kind().check(output))?;
+
+            Ok(std::sync::Arc::new(output))
+        }
+
+        fn accepts(generated: &Generated) -> bool {
+            generated.is::<aoc_runner::kind::Borrowed<Family>>()
+        }
+    }
+
+    impl<A: Takes, K> Lend<A, K> for crate::aoc_factory::generators::Day2Part1
+    where
+        for<'a> <Family as aoc_runner::kind::Family>::Output<'a>: LendsAs<A::Input<'a>, K>,
+    {
+        fn lend<R>(generated: &Generated, f: impl for<'a> FnOnce(&'a A::Input<'a>) -> R) -> R {
+            let output = generated
+                .downcast_ref::<aoc_runner::kind::Borrowed<Family>>()
+                .unwrap();
+            output.with(|output| f(LendsAs::lent(output)))
+        }
+    }
+
+    impl aoc_runner::kind::Generator for crate::aoc_factory::generators::Day2Part2 {
+        const NAME: Option<&'static str> = Some("parse");
+        const ID: Option<&'static str> = Some(module_path!());
+
+        fn generate(input: ArcStr) -> Result<Generated, Box<dyn std::error::Error>> {
+            let output = aoc_runner::kind::Borrowed::<Family>::new(input, parse);
+            output.with(|output| output.aoc_kind().check(output))?;
+
+            Ok(std::sync::Arc::new(output))
+        }
+
+        fn accepts(generated: &Generated) -> bool {
+            generated.is::<aoc_runner::kind::Borrowed<Family>>()
+        }
+    }
+
+    impl<A: Takes, K> Lend<A, K> for crate::aoc_factory::generators::Day2Part2
+    where
+        for<'a> <Family as aoc_runner::kind::Family>::Output<'a>: LendsAs<A::Input<'a>, K>,
+    {
+        fn lend<R>(generated: &Generated, f: impl for<'a> FnOnce(&'a A::Input<'a>) -> R) -> R {
+            let output = generated
+                .downcast_ref::<aoc_runner::kind::Borrowed<Family>>()
+                .unwrap();
+            output.with(|output| f(LendsAs::lent(output)))
+        }
+    }
+}
+
+fn part1(ids: &[&str]) -> u32 {
+    let (nb_double, nb_triple) = ids
+        .iter()
         .map(|l| {
             let mut map = HashMap::with_capacity(l.len());
 
@@ -23,42 +97,93 @@ fn part1(input: &str) -> u32 {
 
     nb_double * nb_triple
 }
+#[allow(unused_imports)]
 mod day2_part1 {
     use super::*;
-    use crate::{Day2Part1, Factory};
-    use aoc_runner::{ArcStr, Runner};
-    use std::borrow::Borrow;
-    use std::error::Error;
-    use std::fmt::Display;
-    use std::marker::PhantomData;
+    use crate::{Day2Part1, Factory, YEAR};
+    use aoc_runner::kind::{Generator, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{inventory, Answer, ArcStr, Generated, Location, Params, Runner, Solution};
+    const PARAMS: &[(&str, &str)] = &[];
+
     impl Day2Part1 for Factory {
-        fn day2_part1(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>> {
-            Ok(Box::new(RunnerStruct::try_gen(input)?))
+        fn day2_part1(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+            runner(
+                &<crate::aoc_factory::inputs::Day2Part1 as Generator>::generate(input)?,
+                Params::from(PARAMS),
+            )
+        }
+    }
+
+    inventory::submit! {
+        Solution::new(
+            YEAR,
+            2,
+            1,
+            None,
+            false,
+            <crate::aoc_factory::inputs::Day2Part1 as Generator>::NAME,
+            <crate::aoc_factory::inputs::Day2Part1 as Generator>::ID,
+            Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+            PARAMS,
+            <crate::aoc_factory::inputs::Day2Part1 as Generator>::generate,
+            runner,
+        )
+    }
+    struct __SolverInput;
+
+    impl Takes for __SolverInput {
+        type Input<'a> = [&'a str];
+    }
+    fn runner(
+        generated: &Generated,
+        params: Params,
+    ) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+        if !<crate::aoc_factory::inputs::Day2Part1 as Generator>::accepts(generated) {
+            return Err("unexpected generator output".into());
         }
+
+        Ok(Box::new(RunnerStruct {
+            input: generated.clone(),
+            params,
+        }))
     }
     pub struct RunnerStruct {
-        input: ArcStr,
-        output: PhantomData<u32>,
+        input: Generated,
+        #[allow(dead_code)]
+        params: Params,
     }
     impl Runner for RunnerStruct {
-        fn gen(input: ArcStr) -> Self {
-            RunnerStruct {
-                input,
-                output: PhantomData,
-            }
+        fn run(&self) -> Answer {
+            self.try_run().expect("failed to run")
         }
-        fn run(&self) -> Box<dyn Display> {
-            Box::new(part1(self.input.borrow()))
+        fn try_run(&self) -> Result<Answer, Box<dyn std::error::Error>> {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day2Part1, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part1(input);
+                    (&output).aoc_kind().answer(output)
+                },
+            )
         }
-        fn bench(&self, black_box: fn(&dyn Display)) {
-            black_box(&part1(self.input.borrow()))
+        fn bench(&self, black_box: fn(&dyn std::fmt::Display)) {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day2Part1, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part1(input);
+                    black_box((&output).aoc_kind().display(&output))
+                },
+            )
         }
     }
 }
 
-fn part1_fnv(input: &str) -> u32 {
-    let (nb_double, nb_triple) = input
-        .lines()
+fn part1_fnv(ids: &[&str]) -> u32 {
+    let (nb_double, nb_triple) = ids
+        .iter()
         .map(|l| {
             let mut map = FnvHashMap::default();
             map.reserve(l.len());
@@ -80,44 +205,93 @@ fn part1_fnv(input: &str) -> u32 {
     nb_double * nb_triple
 }
 
+#[allow(unused_imports)]
 mod day2_part1_fnv {
     use super::*;
-    use crate::{Day2Part1FNV, Factory};
-    use aoc_runner::{ArcStr, Runner};
-    use std::borrow::Borrow;
-    use std::error::Error;
-    use std::fmt::Display;
-    use std::marker::PhantomData;
+    use crate::{Day2Part1FNV, Factory, YEAR};
+    use aoc_runner::kind::{Generator, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{inventory, Answer, ArcStr, Generated, Location, Params, Runner, Solution};
+    const PARAMS: &[(&str, &str)] = &[];
+
     impl Day2Part1FNV for Factory {
-        fn day2_part1_fnv(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>> {
-            Ok(Box::new(RunnerStruct::try_gen(input)?))
+        fn day2_part1_fnv(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+            runner(
+                &<crate::aoc_factory::inputs::Day2Part1FNV as Generator>::generate(input)?,
+                Params::from(PARAMS),
+            )
+        }
+    }
+
+    inventory::submit! {
+        Solution::new(
+            YEAR,
+            2,
+            1,
+            Some("Fnv"),
+            false,
+            <crate::aoc_factory::inputs::Day2Part1FNV as Generator>::NAME,
+            <crate::aoc_factory::inputs::Day2Part1FNV as Generator>::ID,
+            Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+            PARAMS,
+            <crate::aoc_factory::inputs::Day2Part1FNV as Generator>::generate,
+            runner,
+        )
+    }
+    struct __SolverInput;
+
+    impl Takes for __SolverInput {
+        type Input<'a> = [&'a str];
+    }
+    fn runner(
+        generated: &Generated,
+        params: Params,
+    ) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+        if !<crate::aoc_factory::inputs::Day2Part1FNV as Generator>::accepts(generated) {
+            return Err("unexpected generator output".into());
         }
+
+        Ok(Box::new(RunnerStruct {
+            input: generated.clone(),
+            params,
+        }))
     }
     pub struct RunnerStruct {
-        input: ArcStr,
-        output: PhantomData<u32>,
+        input: Generated,
+        #[allow(dead_code)]
+        params: Params,
     }
     impl Runner for RunnerStruct {
-        fn gen(input: ArcStr) -> Self {
-            RunnerStruct {
-                input,
-                output: PhantomData,
-            }
+        fn run(&self) -> Answer {
+            self.try_run().expect("failed to run")
         }
-        fn run(&self) -> Box<dyn Display> {
-            Box::new(part1_fnv(self.input.borrow()))
+        fn try_run(&self) -> Result<Answer, Box<dyn std::error::Error>> {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day2Part1FNV, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part1_fnv(input);
+                    (&output).aoc_kind().answer(output)
+                },
+            )
         }
-        fn bench(&self, black_box: fn(&dyn Display)) {
-            black_box(&part1_fnv(self.input.borrow()))
+        fn bench(&self, black_box: fn(&dyn std::fmt::Display)) {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day2Part1FNV, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part1_fnv(input);
+                    black_box((&output).aoc_kind().display(&output))
+                },
+            )
         }
     }
 }
 
-fn part2(input: &str) -> String {
-    let lines = input.lines();
-
-    for (i, l1) in lines.clone().enumerate() {
-        for (_, l2) in lines.clone().enumerate().filter(|&(j, _)| i != j) {
+fn part2(ids: &[&str]) -> String {
+    for (i, l1) in ids.iter().enumerate() {
+        for (_, l2) in ids.iter().enumerate().filter(|&(j, _)| i != j) {
             let filtered: String = l1
                 .chars()
                 .zip(l2.chars())
@@ -133,35 +307,86 @@ fn part2(input: &str) -> String {
     unreachable!()
 }
 
+#[allow(unused_imports)]
 mod day2_part2 {
     use super::*;
-    use crate::{Day2Part2, Factory};
-    use aoc_runner::{ArcStr, Runner};
-    use std::borrow::Borrow;
-    use std::error::Error;
-    use std::fmt::Display;
-    use std::marker::PhantomData;
+    use crate::{Day2Part2, Factory, YEAR};
+    use aoc_runner::kind::{Generator, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{inventory, Answer, ArcStr, Generated, Location, Params, Runner, Solution};
+    const PARAMS: &[(&str, &str)] = &[];
+
     impl Day2Part2 for Factory {
-        fn day2_part2(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>> {
-            Ok(Box::new(RunnerStruct::try_gen(input)?))
+        fn day2_part2(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+            runner(
+                &<crate::aoc_factory::inputs::Day2Part2 as Generator>::generate(input)?,
+                Params::from(PARAMS),
+            )
+        }
+    }
+
+    inventory::submit! {
+        Solution::new(
+            YEAR,
+            2,
+            2,
+            None,
+            false,
+            <crate::aoc_factory::inputs::Day2Part2 as Generator>::NAME,
+            <crate::aoc_factory::inputs::Day2Part2 as Generator>::ID,
+            Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+            PARAMS,
+            <crate::aoc_factory::inputs::Day2Part2 as Generator>::generate,
+            runner,
+        )
+    }
+    struct __SolverInput;
+
+    impl Takes for __SolverInput {
+        type Input<'a> = [&'a str];
+    }
+    fn runner(
+        generated: &Generated,
+        params: Params,
+    ) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+        if !<crate::aoc_factory::inputs::Day2Part2 as Generator>::accepts(generated) {
+            return Err("unexpected generator output".into());
         }
+
+        Ok(Box::new(RunnerStruct {
+            input: generated.clone(),
+            params,
+        }))
     }
     pub struct RunnerStruct {
-        input: ArcStr,
-        output: PhantomData<String>,
+        input: Generated,
+        #[allow(dead_code)]
+        params: Params,
     }
     impl Runner for RunnerStruct {
-        fn gen(input: ArcStr) -> Self {
-            RunnerStruct {
-                input,
-                output: PhantomData,
-            }
+        fn run(&self) -> Answer {
+            self.try_run().expect("failed to run")
         }
-        fn run(&self) -> Box<dyn Display> {
-            Box::new(part2(self.input.borrow()))
+        fn try_run(&self) -> Result<Answer, Box<dyn std::error::Error>> {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day2Part2, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part2(input);
+                    (&output).aoc_kind().answer(output)
+                },
+            )
         }
-        fn bench(&self, black_box: fn(&dyn Display)) {
-            black_box(&part2(self.input.borrow()))
+        fn bench(&self, black_box: fn(&dyn std::fmt::Display)) {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day2Part2, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part2(input);
+                    black_box((&output).aoc_kind().display(&output))
+                },
+            )
         }
     }
 }
@@ -175,11 +400,11 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(INPUT_PART1), 12);
+        assert_eq!(part1(&parse(INPUT_PART1)), 12);
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(INPUT_PART2), "fgij");
+        assert_eq!(part2(&parse(INPUT_PART2)), "fgij");
     }
 }
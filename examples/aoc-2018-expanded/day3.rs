@@ -73,6 +73,72 @@ fn parse(input: &str) -> Result<Vec<Claim>, Box<dyn Error>> {
         .collect()
 }
 
+#[doc(hidden)]
+#[allow(unused_imports, non_snake_case)]
+mod __aoc_gen_day3_part1_parse {
+    use super::*;
+    use aoc_runner::kind::{Lend, LendsAs, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{ArcStr, Generated};
+
+    impl aoc_runner::kind::Generator for crate::aoc_factory::generators::Day3Part1 {
+        const NAME: Option<&'static str> = Some("parse");
+        const ID: Option<&'static str> = Some(module_path!());
+
+        fn generate(input: ArcStr) -> Result<Generated, Box<dyn std::error::Error>> {
+            let output = parse(std::borrow::Borrow::borrow(&input));
+            let output = (&output).aoc_kind().checked(output)?;
+
+            Ok(std::sync::Arc::new(output))
+        }
+
+        fn accepts(generated: &Generated) -> bool {
+            generated.is::<Result<Vec<Claim>, Box<dyn Error>>>()
+        }
+    }
+
+    impl<A: Takes, K> Lend<A, K> for crate::aoc_factory::generators::Day3Part1
+    where
+        for<'a> Result<Vec<Claim>, Box<dyn Error>>: LendsAs<A::Input<'a>, K>,
+    {
+        fn lend<R>(generated: &Generated, f: impl for<'a> FnOnce(&'a A::Input<'a>) -> R) -> R {
+            f(LendsAs::lent(
+                generated
+                    .downcast_ref::<Result<Vec<Claim>, Box<dyn Error>>>()
+                    .unwrap(),
+            ))
+        }
+    }
+
+    impl aoc_runner::kind::Generator for crate::aoc_factory::generators::Day3Part2 {
+        const NAME: Option<&'static str> = Some("parse");
+        const ID: Option<&'static str> = Some(module_path!());
+
+        fn generate(input: ArcStr) -> Result<Generated, Box<dyn std::error::Error>> {
+            let output = parse(std::borrow::Borrow::borrow(&input));
+            let output = (&output).aoc_kind().checked(output)?;
+
+            Ok(std::sync::Arc::new(output))
+        }
+
+        fn accepts(generated: &Generated) -> bool {
+            generated.is::<Result<Vec<Claim>, Box<dyn Error>>>()
+        }
+    }
+
+    impl<A: Takes, K> Lend<A, K> for crate::aoc_factory::generators::Day3Part2
+    where
+        for<'a> Result<Vec<Claim>, Box<dyn Error>>: LendsAs<A::Input<'a>, K>,
+    {
+        fn lend<R>(generated: &Generated, f: impl for<'a> FnOnce(&'a A::Input<'a>) -> R) -> R {
+            f(LendsAs::lent(
+                generated
+                    .downcast_ref::<Result<Vec<Claim>, Box<dyn Error>>>()
+                    .unwrap(),
+            ))
+        }
+    }
+}
+
 fn part1(claims: &[Claim]) -> usize {
     let mut overlaps = FnvHashSet::default();
 
@@ -91,38 +157,86 @@ fn part1(claims: &[Claim]) -> usize {
     overlaps.len()
 }
 
+#[allow(unused_imports)]
 mod day3_part1 {
     use super::*;
-    use crate::{Day3Part1, Factory};
-    use aoc_runner::{ArcStr, Runner};
-    use std::borrow::Borrow;
-    use std::error::Error;
-    use std::fmt::Display;
-    use std::marker::PhantomData;
+    use crate::{Day3Part1, Factory, YEAR};
+    use aoc_runner::kind::{Generator, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{inventory, Answer, ArcStr, Generated, Location, Params, Runner, Solution};
+    const PARAMS: &[(&str, &str)] = &[];
+
     impl Day3Part1 for Factory {
-        fn day3_part1(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>> {
-            Ok(Box::new(RunnerStruct::try_gen(input)?))
+        fn day3_part1(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+            runner(
+                &<crate::aoc_factory::inputs::Day3Part1 as Generator>::generate(input)?,
+                Params::from(PARAMS),
+            )
         }
     }
+
+    inventory::submit! {
+        Solution::new(
+            YEAR,
+            3,
+            1,
+            None,
+            false,
+            <crate::aoc_factory::inputs::Day3Part1 as Generator>::NAME,
+            <crate::aoc_factory::inputs::Day3Part1 as Generator>::ID,
+            Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+            PARAMS,
+            <crate::aoc_factory::inputs::Day3Part1 as Generator>::generate,
+            runner,
+        )
+    }
+    struct __SolverInput;
+
+    impl Takes for __SolverInput {
+        type Input<'a> = [Claim];
+    }
+    fn runner(
+        generated: &Generated,
+        params: Params,
+    ) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+        if !<crate::aoc_factory::inputs::Day3Part1 as Generator>::accepts(generated) {
+            return Err("unexpected generator output".into());
+        }
+
+        Ok(Box::new(RunnerStruct {
+            input: generated.clone(),
+            params,
+        }))
+    }
     pub struct RunnerStruct {
-        input: Vec<Claim>,
-        output: PhantomData<usize>,
+        input: Generated,
+        #[allow(dead_code)]
+        params: Params,
     }
     impl Runner for RunnerStruct {
-        fn gen(input: ArcStr) -> Self {
-            Self::try_gen(input).expect("failed to generate input")
-        }
-        fn try_gen(input: ArcStr) -> Result<Self, Box<dyn Error>> {
-            Ok(RunnerStruct {
-                input: parse(input.borrow())?,
-                output: PhantomData,
-            })
+        fn run(&self) -> Answer {
+            self.try_run().expect("failed to run")
         }
-        fn run(&self) -> Box<dyn Display> {
-            Box::new(part1(self.input.borrow()))
+        fn try_run(&self) -> Result<Answer, Box<dyn std::error::Error>> {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day3Part1, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part1(input);
+                    (&output).aoc_kind().answer(output)
+                },
+            )
         }
-        fn bench(&self, black_box: fn(&dyn Display)) {
-            black_box(&part1(self.input.borrow()))
+        fn bench(&self, black_box: fn(&dyn std::fmt::Display)) {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day3Part1, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part1(input);
+                    black_box((&output).aoc_kind().display(&output))
+                },
+            )
         }
     }
 }
@@ -141,43 +255,86 @@ fn part2(claims: &[Claim]) -> Option<u32> {
     })
 }
 
+#[allow(unused_imports)]
 mod day3_part2 {
     use super::*;
-    use crate::{Day3Part2, Factory};
-    use aoc_runner::{ArcStr, Runner};
-    use std::borrow::Borrow;
-    use std::error::Error;
-    use std::fmt::Display;
-    use std::marker::PhantomData;
+    use crate::{Day3Part2, Factory, YEAR};
+    use aoc_runner::kind::{Generator, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{inventory, Answer, ArcStr, Generated, Location, Params, Runner, Solution};
+    const PARAMS: &[(&str, &str)] = &[];
+
     impl Day3Part2 for Factory {
-        fn day3_part2(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>> {
-            Ok(Box::new(RunnerStruct::try_gen(input)?))
+        fn day3_part2(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+            runner(
+                &<crate::aoc_factory::inputs::Day3Part2 as Generator>::generate(input)?,
+                Params::from(PARAMS),
+            )
         }
     }
+
+    inventory::submit! {
+        Solution::new(
+            YEAR,
+            3,
+            2,
+            None,
+            false,
+            <crate::aoc_factory::inputs::Day3Part2 as Generator>::NAME,
+            <crate::aoc_factory::inputs::Day3Part2 as Generator>::ID,
+            Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+            PARAMS,
+            <crate::aoc_factory::inputs::Day3Part2 as Generator>::generate,
+            runner,
+        )
+    }
+    struct __SolverInput;
+
+    impl Takes for __SolverInput {
+        type Input<'a> = [Claim];
+    }
+    fn runner(
+        generated: &Generated,
+        params: Params,
+    ) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+        if !<crate::aoc_factory::inputs::Day3Part2 as Generator>::accepts(generated) {
+            return Err("unexpected generator output".into());
+        }
+
+        Ok(Box::new(RunnerStruct {
+            input: generated.clone(),
+            params,
+        }))
+    }
     pub struct RunnerStruct {
-        input: Vec<Claim>,
-        output: PhantomData<u32>,
+        input: Generated,
+        #[allow(dead_code)]
+        params: Params,
     }
     impl Runner for RunnerStruct {
-        fn gen(input: ArcStr) -> Self {
-            Self::try_gen(input).expect("failed to generate input")
-        }
-        fn try_gen(input: ArcStr) -> Result<Self, Box<dyn Error>> {
-            Ok(RunnerStruct {
-                input: parse(input.borrow())?,
-                output: PhantomData,
-            })
-        }
-        fn run(&self) -> Box<dyn Display> {
+        fn run(&self) -> Answer {
             self.try_run().expect("failed to run")
         }
-        fn try_run(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-            Ok(Box::new(
-                part2(self.input.borrow()).ok_or("runner produce no value")?,
-            ))
+        fn try_run(&self) -> Result<Answer, Box<dyn std::error::Error>> {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day3Part2, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part2(input);
+                    (&output).aoc_kind().answer(output)
+                },
+            )
         }
-        fn bench(&self, black_box: fn(&dyn Display)) {
-            black_box(&part2(self.input.borrow()).unwrap())
+        fn bench(&self, black_box: fn(&dyn std::fmt::Display)) {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day3Part2, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part2(input);
+                    black_box((&output).aoc_kind().display(&output))
+                },
+            )
         }
     }
 }
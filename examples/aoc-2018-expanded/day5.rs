@@ -12,35 +12,86 @@ fn part1(input: &[u8]) -> usize {
     reduce(input)
 }
 
+#[allow(unused_imports)]
 mod day5_part1 {
     use super::*;
-    use crate::{Day5Part1, Factory};
-    use aoc_runner::{ArcStr, Runner};
-    use std::borrow::Borrow;
-    use std::error::Error;
-    use std::fmt::Display;
-    use std::marker::PhantomData;
+    use crate::{Day5Part1, Factory, YEAR};
+    use aoc_runner::kind::{Generator, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{inventory, Answer, ArcStr, Generated, Location, Params, Runner, Solution};
+    const PARAMS: &[(&str, &str)] = &[];
+
     impl Day5Part1 for Factory {
-        fn day5_part1(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>> {
-            Ok(Box::new(RunnerStruct::try_gen(input)?))
+        fn day5_part1(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+            runner(
+                &<crate::aoc_factory::inputs::Day5Part1 as Generator>::generate(input)?,
+                Params::from(PARAMS),
+            )
+        }
+    }
+
+    inventory::submit! {
+        Solution::new(
+            YEAR,
+            5,
+            1,
+            None,
+            false,
+            <crate::aoc_factory::inputs::Day5Part1 as Generator>::NAME,
+            <crate::aoc_factory::inputs::Day5Part1 as Generator>::ID,
+            Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+            PARAMS,
+            <crate::aoc_factory::inputs::Day5Part1 as Generator>::generate,
+            runner,
+        )
+    }
+    struct __SolverInput;
+
+    impl Takes for __SolverInput {
+        type Input<'a> = [u8];
+    }
+    fn runner(
+        generated: &Generated,
+        params: Params,
+    ) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+        if !<crate::aoc_factory::inputs::Day5Part1 as Generator>::accepts(generated) {
+            return Err("unexpected generator output".into());
         }
+
+        Ok(Box::new(RunnerStruct {
+            input: generated.clone(),
+            params,
+        }))
     }
     pub struct RunnerStruct {
-        input: ArcStr,
-        output: PhantomData<usize>,
+        input: Generated,
+        #[allow(dead_code)]
+        params: Params,
     }
     impl Runner for RunnerStruct {
-        fn gen(input: ArcStr) -> Self {
-            RunnerStruct {
-                input,
-                output: PhantomData,
-            }
+        fn run(&self) -> Answer {
+            self.try_run().expect("failed to run")
         }
-        fn run(&self) -> Box<dyn Display> {
-            Box::new(part1(self.input.borrow()))
+        fn try_run(&self) -> Result<Answer, Box<dyn std::error::Error>> {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day5Part1, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part1(input);
+                    (&output).aoc_kind().answer(output)
+                },
+            )
         }
-        fn bench(&self, black_box: fn(&dyn Display)) {
-            black_box(&part1(self.input.borrow()))
+        fn bench(&self, black_box: fn(&dyn std::fmt::Display)) {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day5Part1, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part1(input);
+                    black_box((&output).aoc_kind().display(&output))
+                },
+            )
         }
     }
 }
@@ -50,40 +101,86 @@ fn part2(input: &[u8]) -> Option<usize> {
         .map(|c| reduce(input.iter().filter(|&&a| a != c && a != c + DIFF)))
         .min()
 }
+#[allow(unused_imports)]
 mod day5_part2 {
     use super::*;
-    use crate::{Day5Part2, Factory};
-    use aoc_runner::{ArcStr, Runner};
-    use std::borrow::Borrow;
-    use std::error::Error;
-    use std::fmt::Display;
-    use std::marker::PhantomData;
+    use crate::{Day5Part2, Factory, YEAR};
+    use aoc_runner::kind::{Generator, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{inventory, Answer, ArcStr, Generated, Location, Params, Runner, Solution};
+    const PARAMS: &[(&str, &str)] = &[];
+
     impl Day5Part2 for Factory {
-        fn day5_part2(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>> {
-            Ok(Box::new(RunnerStruct::try_gen(input)?))
+        fn day5_part2(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+            runner(
+                &<crate::aoc_factory::inputs::Day5Part2 as Generator>::generate(input)?,
+                Params::from(PARAMS),
+            )
+        }
+    }
+
+    inventory::submit! {
+        Solution::new(
+            YEAR,
+            5,
+            2,
+            None,
+            false,
+            <crate::aoc_factory::inputs::Day5Part2 as Generator>::NAME,
+            <crate::aoc_factory::inputs::Day5Part2 as Generator>::ID,
+            Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+            PARAMS,
+            <crate::aoc_factory::inputs::Day5Part2 as Generator>::generate,
+            runner,
+        )
+    }
+    struct __SolverInput;
+
+    impl Takes for __SolverInput {
+        type Input<'a> = [u8];
+    }
+    fn runner(
+        generated: &Generated,
+        params: Params,
+    ) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+        if !<crate::aoc_factory::inputs::Day5Part2 as Generator>::accepts(generated) {
+            return Err("unexpected generator output".into());
         }
+
+        Ok(Box::new(RunnerStruct {
+            input: generated.clone(),
+            params,
+        }))
     }
     pub struct RunnerStruct {
-        input: ArcStr,
-        output: PhantomData<usize>,
+        input: Generated,
+        #[allow(dead_code)]
+        params: Params,
     }
     impl Runner for RunnerStruct {
-        fn gen(input: ArcStr) -> Self {
-            RunnerStruct {
-                input,
-                output: PhantomData,
-            }
-        }
-        fn run(&self) -> Box<dyn Display> {
+        fn run(&self) -> Answer {
             self.try_run().expect("failed to run")
         }
-        fn try_run(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-            Ok(Box::new(
-                part2(self.input.borrow()).ok_or("runner produce no value")?,
-            ))
+        fn try_run(&self) -> Result<Answer, Box<dyn std::error::Error>> {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day5Part2, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part2(input);
+                    (&output).aoc_kind().answer(output)
+                },
+            )
         }
-        fn bench(&self, black_box: fn(&dyn Display)) {
-            black_box(&part2(self.input.borrow()).unwrap())
+        fn bench(&self, black_box: fn(&dyn std::fmt::Display)) {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day5Part2, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part2(input);
+                    black_box((&output).aoc_kind().display(&output))
+                },
+            )
         }
     }
 }
@@ -92,35 +189,86 @@ fn part1_stack(input: &[u8]) -> usize {
     stack(input)
 }
 
+#[allow(unused_imports)]
 mod day5_part1_stack {
     use super::*;
-    use crate::{Day5Part1STACK, Factory};
-    use aoc_runner::{ArcStr, Runner};
-    use std::borrow::Borrow;
-    use std::error::Error;
-    use std::fmt::Display;
-    use std::marker::PhantomData;
+    use crate::{Day5Part1STACK, Factory, YEAR};
+    use aoc_runner::kind::{Generator, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{inventory, Answer, ArcStr, Generated, Location, Params, Runner, Solution};
+    const PARAMS: &[(&str, &str)] = &[];
+
     impl Day5Part1STACK for Factory {
-        fn day5_part1_stack(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>> {
-            Ok(Box::new(RunnerStruct::try_gen(input)?))
+        fn day5_part1_stack(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+            runner(
+                &<crate::aoc_factory::inputs::Day5Part1STACK as Generator>::generate(input)?,
+                Params::from(PARAMS),
+            )
         }
     }
+
+    inventory::submit! {
+        Solution::new(
+            YEAR,
+            5,
+            1,
+            Some("Stack"),
+            false,
+            <crate::aoc_factory::inputs::Day5Part1STACK as Generator>::NAME,
+            <crate::aoc_factory::inputs::Day5Part1STACK as Generator>::ID,
+            Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+            PARAMS,
+            <crate::aoc_factory::inputs::Day5Part1STACK as Generator>::generate,
+            runner,
+        )
+    }
+    struct __SolverInput;
+
+    impl Takes for __SolverInput {
+        type Input<'a> = [u8];
+    }
+    fn runner(
+        generated: &Generated,
+        params: Params,
+    ) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+        if !<crate::aoc_factory::inputs::Day5Part1STACK as Generator>::accepts(generated) {
+            return Err("unexpected generator output".into());
+        }
+
+        Ok(Box::new(RunnerStruct {
+            input: generated.clone(),
+            params,
+        }))
+    }
     pub struct RunnerStruct {
-        input: ArcStr,
-        output: PhantomData<usize>,
+        input: Generated,
+        #[allow(dead_code)]
+        params: Params,
     }
     impl Runner for RunnerStruct {
-        fn gen(input: ArcStr) -> Self {
-            RunnerStruct {
-                input,
-                output: PhantomData,
-            }
+        fn run(&self) -> Answer {
+            self.try_run().expect("failed to run")
         }
-        fn run(&self) -> Box<dyn Display> {
-            Box::new(part1_stack(self.input.borrow()))
+        fn try_run(&self) -> Result<Answer, Box<dyn std::error::Error>> {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day5Part1STACK, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part1_stack(input);
+                    (&output).aoc_kind().answer(output)
+                },
+            )
         }
-        fn bench(&self, black_box: fn(&dyn Display)) {
-            black_box(&part1_stack(self.input.borrow()))
+        fn bench(&self, black_box: fn(&dyn std::fmt::Display)) {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day5Part1STACK, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part1_stack(input);
+                    black_box((&output).aoc_kind().display(&output))
+                },
+            )
         }
     }
 }
@@ -130,40 +278,305 @@ fn part2_stack(input: &[u8]) -> Option<usize> {
         .map(|c| stack(input.iter().filter(|&&a| a != c && a != c + DIFF)))
         .min()
 }
+#[allow(unused_imports)]
 mod day5_part2_stack {
     use super::*;
-    use crate::{Day5Part2STACK, Factory};
-    use aoc_runner::{ArcStr, Runner};
-    use std::borrow::Borrow;
-    use std::error::Error;
-    use std::fmt::Display;
-    use std::marker::PhantomData;
+    use crate::{Day5Part2STACK, Factory, YEAR};
+    use aoc_runner::kind::{Generator, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{inventory, Answer, ArcStr, Generated, Location, Params, Runner, Solution};
+    const PARAMS: &[(&str, &str)] = &[];
+
     impl Day5Part2STACK for Factory {
-        fn day5_part2_stack(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>> {
-            Ok(Box::new(RunnerStruct::try_gen(input)?))
+        fn day5_part2_stack(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+            runner(
+                &<crate::aoc_factory::inputs::Day5Part2STACK as Generator>::generate(input)?,
+                Params::from(PARAMS),
+            )
         }
     }
+
+    inventory::submit! {
+        Solution::new(
+            YEAR,
+            5,
+            2,
+            Some("Stack"),
+            false,
+            <crate::aoc_factory::inputs::Day5Part2STACK as Generator>::NAME,
+            <crate::aoc_factory::inputs::Day5Part2STACK as Generator>::ID,
+            Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+            PARAMS,
+            <crate::aoc_factory::inputs::Day5Part2STACK as Generator>::generate,
+            runner,
+        )
+    }
+    struct __SolverInput;
+
+    impl Takes for __SolverInput {
+        type Input<'a> = [u8];
+    }
+    fn runner(
+        generated: &Generated,
+        params: Params,
+    ) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+        if !<crate::aoc_factory::inputs::Day5Part2STACK as Generator>::accepts(generated) {
+            return Err("unexpected generator output".into());
+        }
+
+        Ok(Box::new(RunnerStruct {
+            input: generated.clone(),
+            params,
+        }))
+    }
     pub struct RunnerStruct {
-        input: ArcStr,
-        output: PhantomData<usize>,
+        input: Generated,
+        #[allow(dead_code)]
+        params: Params,
     }
     impl Runner for RunnerStruct {
-        fn gen(input: ArcStr) -> Self {
-            RunnerStruct {
-                input,
-                output: PhantomData,
-            }
+        fn run(&self) -> Answer {
+            self.try_run().expect("failed to run")
+        }
+        fn try_run(&self) -> Result<Answer, Box<dyn std::error::Error>> {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day5Part2STACK, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part2_stack(input);
+                    (&output).aoc_kind().answer(output)
+                },
+            )
+        }
+        fn bench(&self, black_box: fn(&dyn std::fmt::Display)) {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day5Part2STACK, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part2_stack(input);
+                    black_box((&output).aoc_kind().display(&output))
+                },
+            )
+        }
+    }
+}
+
+fn both_reduced(input: &[u8]) -> Option<(usize, usize)> {
+    // Units react the same way in the reduced polymer, so part 2 can start from it
+    let polymer = react(input);
+
+    let shortest = (b'A'..=b'Z')
+        .map(|c| stack(polymer.iter().filter(|&&a| a != c && a != c + DIFF)))
+        .min()?;
+
+    Some((polymer.len(), shortest))
+}
+
+#[allow(unused_imports)]
+mod day5_part1_reduced {
+    use super::*;
+    use crate::{Day5Part1REDUCED, Factory, YEAR};
+    use aoc_runner::kind::{Generator, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{inventory, Answer, ArcStr, Generated, Location, Params, Runner, Solution};
+    const PARAMS: &[(&str, &str)] = &[];
+
+    impl Day5Part1REDUCED for Factory {
+        fn day5_part1_reduced(
+            input: ArcStr,
+        ) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+            runner(
+                &<crate::aoc_factory::inputs::Day5Part1REDUCED as Generator>::generate(input)?,
+                Params::from(PARAMS),
+            )
+        }
+    }
+
+    inventory::submit! {
+        Solution::new(
+            YEAR,
+            5,
+            1,
+            Some("Reduced"),
+            true,
+            <crate::aoc_factory::inputs::Day5Part1REDUCED as Generator>::NAME,
+            <crate::aoc_factory::inputs::Day5Part1REDUCED as Generator>::ID,
+            Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+            PARAMS,
+            <crate::aoc_factory::inputs::Day5Part1REDUCED as Generator>::generate,
+            runner,
+        )
+    }
+    struct __SolverInput;
+
+    impl Takes for __SolverInput {
+        type Input<'a> = [u8];
+    }
+    fn runner(
+        generated: &Generated,
+        params: Params,
+    ) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+        if !<crate::aoc_factory::inputs::Day5Part1REDUCED as Generator>::accepts(generated) {
+            return Err("unexpected generator output".into());
         }
-        fn run(&self) -> Box<dyn Display> {
+
+        Ok(Box::new(RunnerStruct {
+            input: generated.clone(),
+            params,
+        }))
+    }
+    pub struct RunnerStruct {
+        input: Generated,
+        #[allow(dead_code)]
+        params: Params,
+    }
+    impl Runner for RunnerStruct {
+        fn run(&self) -> Answer {
             self.try_run().expect("failed to run")
         }
-        fn try_run(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-            Ok(Box::new(
-                part2_stack(self.input.borrow()).ok_or("runner produce no value")?,
-            ))
+        fn try_run(&self) -> Result<Answer, Box<dyn std::error::Error>> {
+            aoc_runner::kind::lend::<
+                crate::aoc_factory::inputs::Day5Part1REDUCED,
+                __SolverInput,
+                _,
+                _,
+            >(&self.input, |input| {
+                let output = both_reduced(input);
+                (&output)
+                    .aoc_kind()
+                    .answers(output)
+                    .map(|answers| answers.0)
+            })
+        }
+        fn try_run_both(&self) -> Result<(Answer, Answer), Box<dyn std::error::Error>> {
+            aoc_runner::kind::lend::<
+                crate::aoc_factory::inputs::Day5Part1REDUCED,
+                __SolverInput,
+                _,
+                _,
+            >(&self.input, |input| {
+                let output = both_reduced(input);
+                (&output).aoc_kind().answers(output)
+            })
         }
-        fn bench(&self, black_box: fn(&dyn Display)) {
-            black_box(&part2_stack(self.input.borrow()).unwrap())
+        fn bench(&self, black_box: fn(&dyn std::fmt::Display)) {
+            aoc_runner::kind::lend::<
+                crate::aoc_factory::inputs::Day5Part1REDUCED,
+                __SolverInput,
+                _,
+                _,
+            >(&self.input, |input| {
+                let output = both_reduced(input);
+                black_box(&aoc_runner::kind::Opaque(&output))
+            })
+        }
+    }
+}
+
+#[allow(unused_imports)]
+mod day5_part2_reduced {
+    use super::*;
+    use crate::{Day5Part2REDUCED, Factory, YEAR};
+    use aoc_runner::kind::{Generator, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{inventory, Answer, ArcStr, Generated, Location, Params, Runner, Solution};
+    const PARAMS: &[(&str, &str)] = &[];
+
+    impl Day5Part2REDUCED for Factory {
+        fn day5_part2_reduced(
+            input: ArcStr,
+        ) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+            runner(
+                &<crate::aoc_factory::inputs::Day5Part2REDUCED as Generator>::generate(input)?,
+                Params::from(PARAMS),
+            )
+        }
+    }
+
+    inventory::submit! {
+        Solution::new(
+            YEAR,
+            5,
+            2,
+            Some("Reduced"),
+            true,
+            <crate::aoc_factory::inputs::Day5Part2REDUCED as Generator>::NAME,
+            <crate::aoc_factory::inputs::Day5Part2REDUCED as Generator>::ID,
+            Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+            PARAMS,
+            <crate::aoc_factory::inputs::Day5Part2REDUCED as Generator>::generate,
+            runner,
+        )
+    }
+    struct __SolverInput;
+
+    impl Takes for __SolverInput {
+        type Input<'a> = [u8];
+    }
+    fn runner(
+        generated: &Generated,
+        params: Params,
+    ) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+        if !<crate::aoc_factory::inputs::Day5Part2REDUCED as Generator>::accepts(generated) {
+            return Err("unexpected generator output".into());
+        }
+
+        Ok(Box::new(RunnerStruct {
+            input: generated.clone(),
+            params,
+        }))
+    }
+    pub struct RunnerStruct {
+        input: Generated,
+        #[allow(dead_code)]
+        params: Params,
+    }
+    impl Runner for RunnerStruct {
+        fn run(&self) -> Answer {
+            self.try_run().expect("failed to run")
+        }
+        fn try_run(&self) -> Result<Answer, Box<dyn std::error::Error>> {
+            aoc_runner::kind::lend::<
+                crate::aoc_factory::inputs::Day5Part2REDUCED,
+                __SolverInput,
+                _,
+                _,
+            >(&self.input, |input| {
+                let output = both_reduced(input);
+                (&output)
+                    .aoc_kind()
+                    .answers(output)
+                    .map(|answers| answers.1)
+            })
+        }
+        fn try_run_both(&self) -> Result<(Answer, Answer), Box<dyn std::error::Error>> {
+            aoc_runner::kind::lend::<
+                crate::aoc_factory::inputs::Day5Part2REDUCED,
+                __SolverInput,
+                _,
+                _,
+            >(&self.input, |input| {
+                let output = both_reduced(input);
+                (&output).aoc_kind().answers(output)
+            })
+        }
+        fn bench(&self, black_box: fn(&dyn std::fmt::Display)) {
+            aoc_runner::kind::lend::<
+                crate::aoc_factory::inputs::Day5Part2REDUCED,
+                __SolverInput,
+                _,
+                _,
+            >(&self.input, |input| {
+                let output = both_reduced(input);
+                black_box(&aoc_runner::kind::Opaque(&output))
+            })
         }
     }
 }
@@ -222,19 +635,20 @@ fn reduce<'a>(polymer: impl IntoIterator<Item = &'a u8>) -> usize {
 }
 
 fn stack<'a>(polymer: impl IntoIterator<Item = &'a u8>) -> usize {
-    polymer
-        .into_iter()
-        .fold(Vec::new(), |mut stack, &unit| {
-            match stack.last() {
-                Some(&other) if diff(other, unit) == DIFF => {
-                    stack.pop();
-                }
-                _ => stack.push(unit),
+    react(polymer).len()
+}
+
+fn react<'a>(polymer: impl IntoIterator<Item = &'a u8>) -> Vec<u8> {
+    polymer.into_iter().fold(Vec::new(), |mut stack, &unit| {
+        match stack.last() {
+            Some(&other) if diff(other, unit) == DIFF => {
+                stack.pop();
             }
+            _ => stack.push(unit),
+        }
 
-            stack
-        })
-        .len()
+        stack
+    })
 }
 
 #[cfg(test)]
@@ -252,4 +666,9 @@ mod tests {
         assert_eq!(part2(b"dabAcCaCBAcCcaDA"), Some(4));
         assert_eq!(part2_stack(b"dabAcCaCBAcCcaDA"), Some(4));
     }
+
+    #[test]
+    fn both_sample() {
+        assert_eq!(both_reduced(b"dabAcCaCBAcCcaDA"), Some((10, 4)));
+    }
 }
@@ -1,3 +1,4 @@
+use aoc_runner::Params;
 use petgraph::Direction;
 use petgraph::Graph;
 use std::cmp::Ordering;
@@ -64,10 +65,74 @@ fn parse(input: &str) -> Result<Graph<Step, ()>, &'static str> {
     Ok(graph.into_graph())
 }
 
-fn part1(graph: &Graph<Step, ()>) -> Result<String, FromUtf8Error> {
-    let mut remaining = graph.clone();
+#[doc(hidden)]
+#[allow(unused_imports, non_snake_case)]
+mod __aoc_gen_day7_part1_parse {
+    use super::*;
+    use aoc_runner::kind::{Lend, LendsAs, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{ArcStr, Generated};
+
+    impl aoc_runner::kind::Generator for crate::aoc_factory::generators::Day7Part1 {
+        const NAME: Option<&'static str> = Some("parse");
+        const ID: Option<&'static str> = Some(module_path!());
+
+        fn generate(input: ArcStr) -> Result<Generated, Box<dyn std::error::Error>> {
+            let output = parse(std::borrow::Borrow::borrow(&input));
+            let output = (&output).aoc_kind().checked(output)?;
+
+            Ok(std::sync::Arc::new(output))
+        }
+
+        fn accepts(generated: &Generated) -> bool {
+            generated.is::<Result<Graph<Step, ()>, &'static str>>()
+        }
+    }
+
+    impl<A: Takes, K> Lend<A, K> for crate::aoc_factory::generators::Day7Part1
+    where
+        for<'a> Result<Graph<Step, ()>, &'static str>: LendsAs<A::Input<'a>, K>,
+    {
+        fn lend<R>(generated: &Generated, f: impl for<'a> FnOnce(&'a A::Input<'a>) -> R) -> R {
+            f(LendsAs::lent(
+                generated
+                    .downcast_ref::<Result<Graph<Step, ()>, &'static str>>()
+                    .unwrap(),
+            ))
+        }
+    }
 
-    let mut seq = Vec::with_capacity(graph.node_count());
+    impl aoc_runner::kind::Generator for crate::aoc_factory::generators::Day7Part2 {
+        const NAME: Option<&'static str> = Some("parse");
+        const ID: Option<&'static str> = Some(module_path!());
+
+        fn generate(input: ArcStr) -> Result<Generated, Box<dyn std::error::Error>> {
+            let output = parse(std::borrow::Borrow::borrow(&input));
+            let output = (&output).aoc_kind().checked(output)?;
+
+            Ok(std::sync::Arc::new(output))
+        }
+
+        fn accepts(generated: &Generated) -> bool {
+            generated.is::<Result<Graph<Step, ()>, &'static str>>()
+        }
+    }
+
+    impl<A: Takes, K> Lend<A, K> for crate::aoc_factory::generators::Day7Part2
+    where
+        for<'a> Result<Graph<Step, ()>, &'static str>: LendsAs<A::Input<'a>, K>,
+    {
+        fn lend<R>(generated: &Generated, f: impl for<'a> FnOnce(&'a A::Input<'a>) -> R) -> R {
+            f(LendsAs::lent(
+                generated
+                    .downcast_ref::<Result<Graph<Step, ()>, &'static str>>()
+                    .unwrap(),
+            ))
+        }
+    }
+}
+
+fn part1(mut remaining: Graph<Step, ()>) -> Result<String, FromUtf8Error> {
+    let mut seq = Vec::with_capacity(remaining.node_count());
 
     loop {
         if let Some(i) = remaining
@@ -81,87 +146,236 @@ fn part1(graph: &Graph<Step, ()>) -> Result<String, FromUtf8Error> {
     }
 }
 
+#[allow(unused_imports)]
 mod day7_part1 {
     use super::*;
-    use crate::{Day7Part1, Factory};
-    use aoc_runner::{ArcStr, Runner};
-    use std::borrow::Borrow;
-    use std::error::Error;
-    use std::fmt::Display;
-    use std::marker::PhantomData;
+    use crate::{Day7Part1, Factory, YEAR};
+    use aoc_runner::kind::{Generator, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{inventory, Answer, ArcStr, Generated, Location, Params, Runner, Solution};
+    const PARAMS: &[(&str, &str)] = &[];
+
     impl Day7Part1 for Factory {
-        fn day7_part1(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>> {
-            Ok(Box::new(RunnerStruct::try_gen(input)?))
+        fn day7_part1(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+            runner(
+                &<crate::aoc_factory::inputs::Day7Part1 as Generator>::generate(input)?,
+                Params::from(PARAMS),
+            )
         }
     }
+
+    inventory::submit! {
+        Solution::new(
+            YEAR,
+            7,
+            1,
+            None,
+            false,
+            <crate::aoc_factory::inputs::Day7Part1 as Generator>::NAME,
+            <crate::aoc_factory::inputs::Day7Part1 as Generator>::ID,
+            Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+            PARAMS,
+            <crate::aoc_factory::inputs::Day7Part1 as Generator>::generate,
+            runner,
+        )
+    }
+    struct __SolverInput;
+
+    impl Takes for __SolverInput {
+        type Input<'a> = Graph<Step, ()>;
+    }
+    fn runner(
+        generated: &Generated,
+        params: Params,
+    ) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+        if !<crate::aoc_factory::inputs::Day7Part1 as Generator>::accepts(generated) {
+            return Err("unexpected generator output".into());
+        }
+
+        Ok(Box::new(RunnerStruct {
+            input: generated.clone(),
+            params,
+        }))
+    }
     pub struct RunnerStruct {
-        input: Graph<Step, ()>,
-        output: PhantomData<String>,
+        input: Generated,
+        #[allow(dead_code)]
+        params: Params,
     }
     impl Runner for RunnerStruct {
-        fn gen(input: ArcStr) -> Self {
-            Self::try_gen(input).expect("failed to generate input")
+        fn run(&self) -> Answer {
+            self.try_run().expect("failed to run")
         }
-        fn try_gen(input: ArcStr) -> Result<Self, Box<dyn Error>> {
-            Ok(RunnerStruct {
-                input: parse(input.borrow())?,
-                output: PhantomData,
-            })
+        fn try_run(&self) -> Result<Answer, Box<dyn std::error::Error>> {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day7Part1, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part1(std::borrow::ToOwned::to_owned(input));
+                    (&output).aoc_kind().answer(output)
+                },
+            )
         }
-        fn run(&self) -> Box<dyn Display> {
-            self.try_run().expect("failed to run")
+        fn bench(&self, black_box: fn(&dyn std::fmt::Display)) {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day7Part1, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = part1(std::borrow::ToOwned::to_owned(input));
+                    black_box((&output).aoc_kind().display(&output))
+                },
+            )
         }
-        fn try_run(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-            Ok(Box::new(part1(self.input.borrow())?))
+
+        fn setup(&self) -> Box<dyn std::any::Any> {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day7Part1, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let input: Box<dyn std::any::Any> =
+                        Box::new(std::borrow::ToOwned::to_owned(input));
+                    input
+                },
+            )
         }
-        fn bench(&self, black_box: fn(&dyn Display)) {
-            black_box(&part1(self.input.borrow()).unwrap())
+
+        fn bench_batched(
+            &self,
+            input: Box<dyn std::any::Any>,
+            black_box: fn(&dyn std::fmt::Display),
+        ) {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day7Part1, __SolverInput, _, _>(
+                &self.input,
+                |like| {
+                    let input = aoc_runner::kind::downcast_as(like, input);
+                    let output = part1(input);
+                    black_box((&output).aoc_kind().display(&output))
+                },
+            )
         }
     }
 }
 
-fn part2(graph: &Graph<Step, ()>) -> u32 {
-    part2_internal(graph, 5, 60)
-}
-
+#[allow(unused_imports)]
 mod day7_part2 {
     use super::*;
-    use crate::{Day7Part2, Factory};
-    use aoc_runner::{ArcStr, Runner};
-    use std::borrow::Borrow;
-    use std::error::Error;
-    use std::fmt::Display;
-    use std::marker::PhantomData;
+    use crate::{Day7Part2, Factory, YEAR};
+    use aoc_runner::kind::{Generator, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{inventory, Answer, ArcStr, Generated, Location, Params, Runner, Solution};
+    const PARAMS: &[(&str, &str)] = &[("workers", "5"), ("base_time", "60")];
+
     impl Day7Part2 for Factory {
-        fn day7_part2(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>> {
-            Ok(Box::new(RunnerStruct::try_gen(input)?))
+        fn day7_part2(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+            runner(
+                &<crate::aoc_factory::inputs::Day7Part2 as Generator>::generate(input)?,
+                Params::from(PARAMS),
+            )
+        }
+    }
+
+    inventory::submit! {
+        Solution::new(
+            YEAR,
+            7,
+            2,
+            None,
+            false,
+            <crate::aoc_factory::inputs::Day7Part2 as Generator>::NAME,
+            <crate::aoc_factory::inputs::Day7Part2 as Generator>::ID,
+            Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+            PARAMS,
+            <crate::aoc_factory::inputs::Day7Part2 as Generator>::generate,
+            runner,
+        )
+    }
+    struct __SolverInput;
+
+    impl Takes for __SolverInput {
+        type Input<'a> = Graph<Step, ()>;
+    }
+    fn runner(
+        generated: &Generated,
+        params: Params,
+    ) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+        if !<crate::aoc_factory::inputs::Day7Part2 as Generator>::accepts(generated) {
+            return Err("unexpected generator output".into());
         }
+
+        Ok(Box::new(RunnerStruct {
+            input: generated.clone(),
+            params,
+        }))
     }
     pub struct RunnerStruct {
-        input: Graph<Step, ()>,
-        output: PhantomData<u32>,
+        input: Generated,
+        #[allow(dead_code)]
+        params: Params,
     }
     impl Runner for RunnerStruct {
-        fn gen(input: ArcStr) -> Self {
-            Self::try_gen(input).expect("failed to generate input")
+        fn run(&self) -> Answer {
+            self.try_run().expect("failed to run")
         }
-        fn try_gen(input: ArcStr) -> Result<Self, Box<dyn Error>> {
-            Ok(RunnerStruct {
-                input: parse(input.borrow())?,
-                output: PhantomData,
-            })
+        fn try_run(&self) -> Result<Answer, Box<dyn std::error::Error>> {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day7Part2, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let mut input = std::borrow::ToOwned::to_owned(input);
+                    let output =
+                        part2(std::borrow::BorrowMut::borrow_mut(&mut input), &self.params);
+                    (&output).aoc_kind().answer(output)
+                },
+            )
         }
-        fn run(&self) -> Box<dyn Display> {
-            Box::new(part2(self.input.borrow()))
+        fn bench(&self, black_box: fn(&dyn std::fmt::Display)) {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day7Part2, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let mut input = std::borrow::ToOwned::to_owned(input);
+                    let output =
+                        part2(std::borrow::BorrowMut::borrow_mut(&mut input), &self.params);
+                    black_box((&output).aoc_kind().display(&output))
+                },
+            )
         }
-        fn bench(&self, black_box: fn(&dyn Display)) {
-            black_box(&part2(self.input.borrow()))
+
+        fn setup(&self) -> Box<dyn std::any::Any> {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day7Part2, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let input: Box<dyn std::any::Any> =
+                        Box::new(std::borrow::ToOwned::to_owned(input));
+                    input
+                },
+            )
+        }
+
+        fn bench_batched(
+            &self,
+            input: Box<dyn std::any::Any>,
+            black_box: fn(&dyn std::fmt::Display),
+        ) {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day7Part2, __SolverInput, _, _>(
+                &self.input,
+                |like| {
+                    let input = aoc_runner::kind::downcast_as(like, input);
+                    let mut input = input;
+                    let output =
+                        part2(std::borrow::BorrowMut::borrow_mut(&mut input), &self.params);
+                    black_box((&output).aoc_kind().display(&output))
+                },
+            )
         }
     }
 }
 
-fn part2_internal(graph: &Graph<Step, ()>, nb_worker: usize, base_time: u32) -> u32 {
-    let mut remaining = graph.clone();
+fn part2(remaining: &mut Graph<Step, ()>, params: &Params) -> u32 {
+    let nb_worker: usize = params.get("workers");
+    let base_time: u32 = params.get("base_time");
+
     let mut workers = vec![(None, 0); nb_worker];
     let mut started = Vec::with_capacity(remaining.node_count());
 
@@ -232,13 +446,15 @@ Step F must be finished before step E can begin.";
     fn part1_example() {
         let graph = parse(INPUT).unwrap();
 
-        assert_eq!(part1(&graph).unwrap(), "CABDFE".to_string());
+        assert_eq!(part1(graph).unwrap(), "CABDFE".to_string());
     }
 
     #[test]
     fn part2_example() {
-        let graph = parse(INPUT).unwrap();
+        let mut graph = parse(INPUT).unwrap();
+
+        let params = Params::new().with("workers", 2).with("base_time", 0);
 
-        assert_eq!(part2_internal(&graph, 2, 0), 15);
+        assert_eq!(part2(&mut graph, &params), 15);
     }
 }
@@ -6,6 +6,10 @@ struct Node {
 }
 
 impl Node {
+    fn parse(input: &str) -> Option<Node> {
+        Node::from_iter(&mut input.split_whitespace().map(|s| s.parse().unwrap()))
+    }
+
     fn from_iter<I>(iter: &mut I) -> Option<Node>
     where
         I: Iterator<Item = Data>,
@@ -45,86 +49,232 @@ impl Node {
     }
 }
 
-fn parse(input: &str) -> Option<Node> {
-    Node::from_iter(&mut input.split_whitespace().map(|s| s.parse().unwrap()))
-}
+#[doc(hidden)]
+#[allow(unused_imports, non_snake_case)]
+mod __aoc_gen_day8_part1_Node__parse {
+    use super::*;
+    use aoc_runner::kind::{Lend, LendsAs, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{ArcStr, Generated};
+
+    impl aoc_runner::kind::Generator for crate::aoc_factory::generators::Day8Part1 {
+        const NAME: Option<&'static str> = Some("Node::parse");
+        const ID: Option<&'static str> = Some(module_path!());
+
+        fn generate(input: ArcStr) -> Result<Generated, Box<dyn std::error::Error>> {
+            let output = <Node>::parse(std::borrow::Borrow::borrow(&input));
+            let output = (&output).aoc_kind().checked(output)?;
+
+            Ok(std::sync::Arc::new(output))
+        }
+
+        fn accepts(generated: &Generated) -> bool {
+            generated.is::<Option<Node>>()
+        }
+    }
+
+    impl<A: Takes, K> Lend<A, K> for crate::aoc_factory::generators::Day8Part1
+    where
+        for<'a> Option<Node>: LendsAs<A::Input<'a>, K>,
+    {
+        fn lend<R>(generated: &Generated, f: impl for<'a> FnOnce(&'a A::Input<'a>) -> R) -> R {
+            f(LendsAs::lent(
+                generated.downcast_ref::<Option<Node>>().unwrap(),
+            ))
+        }
+    }
+
+    impl aoc_runner::kind::Generator for crate::aoc_factory::generators::Day8Part2 {
+        const NAME: Option<&'static str> = Some("Node::parse");
+        const ID: Option<&'static str> = Some(module_path!());
+
+        fn generate(input: ArcStr) -> Result<Generated, Box<dyn std::error::Error>> {
+            let output = <Node>::parse(std::borrow::Borrow::borrow(&input));
+            let output = (&output).aoc_kind().checked(output)?;
 
-fn part1(root: &Node) -> Data {
-    root.checksum()
+            Ok(std::sync::Arc::new(output))
+        }
+
+        fn accepts(generated: &Generated) -> bool {
+            generated.is::<Option<Node>>()
+        }
+    }
+
+    impl<A: Takes, K> Lend<A, K> for crate::aoc_factory::generators::Day8Part2
+    where
+        for<'a> Option<Node>: LendsAs<A::Input<'a>, K>,
+    {
+        fn lend<R>(generated: &Generated, f: impl for<'a> FnOnce(&'a A::Input<'a>) -> R) -> R {
+            f(LendsAs::lent(
+                generated.downcast_ref::<Option<Node>>().unwrap(),
+            ))
+        }
+    }
 }
 
+#[allow(unused_imports)]
 mod day8_part1 {
     use super::*;
-    use crate::{Day8Part1, Factory};
-    use aoc_runner::{ArcStr, Runner};
-    use std::borrow::Borrow;
-    use std::error::Error;
-    use std::fmt::Display;
-    use std::marker::PhantomData;
+    use crate::{Day8Part1, Factory, YEAR};
+    use aoc_runner::kind::{Generator, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{inventory, Answer, ArcStr, Generated, Location, Params, Runner, Solution};
+    const PARAMS: &[(&str, &str)] = &[];
+
     impl Day8Part1 for Factory {
-        fn day8_part1(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>> {
-            Ok(Box::new(RunnerStruct::try_gen(input)?))
+        fn day8_part1(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+            runner(
+                &<crate::aoc_factory::inputs::Day8Part1 as Generator>::generate(input)?,
+                Params::from(PARAMS),
+            )
+        }
+    }
+
+    inventory::submit! {
+        Solution::new(
+            YEAR,
+            8,
+            1,
+            None,
+            false,
+            <crate::aoc_factory::inputs::Day8Part1 as Generator>::NAME,
+            <crate::aoc_factory::inputs::Day8Part1 as Generator>::ID,
+            Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+            PARAMS,
+            <crate::aoc_factory::inputs::Day8Part1 as Generator>::generate,
+            runner,
+        )
+    }
+    struct __SolverInput;
+
+    impl Takes for __SolverInput {
+        type Input<'a> = Node;
+    }
+    fn runner(
+        generated: &Generated,
+        params: Params,
+    ) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+        if !<crate::aoc_factory::inputs::Day8Part1 as Generator>::accepts(generated) {
+            return Err("unexpected generator output".into());
         }
+
+        Ok(Box::new(RunnerStruct {
+            input: generated.clone(),
+            params,
+        }))
     }
     pub struct RunnerStruct {
-        input: Node,
-        output: PhantomData<Data>,
+        input: Generated,
+        #[allow(dead_code)]
+        params: Params,
     }
     impl Runner for RunnerStruct {
-        fn gen(input: ArcStr) -> Self {
-            Self::try_gen(input).expect("failed to generate input")
-        }
-        fn try_gen(input: ArcStr) -> Result<Self, Box<dyn Error>> {
-            Ok(RunnerStruct {
-                input: parse(input.borrow()).ok_or("generator produce no value")?,
-                output: PhantomData,
-            })
+        fn run(&self) -> Answer {
+            self.try_run().expect("failed to run")
         }
-        fn run(&self) -> Box<dyn Display> {
-            Box::new(part1(self.input.borrow()))
+        fn try_run(&self) -> Result<Answer, Box<dyn std::error::Error>> {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day8Part1, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = <Node>::checksum(input);
+                    (&output).aoc_kind().answer(output)
+                },
+            )
         }
-        fn bench(&self, black_box: fn(&dyn Display)) {
-            black_box(&part1(self.input.borrow()))
+        fn bench(&self, black_box: fn(&dyn std::fmt::Display)) {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day8Part1, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = <Node>::checksum(input);
+                    black_box((&output).aoc_kind().display(&output))
+                },
+            )
         }
     }
 }
 
-fn part2(root: &Node) -> Data {
-    root.value()
-}
-
+#[allow(unused_imports)]
 mod day8_part2 {
     use super::*;
-    use crate::{Day8Part2, Factory};
-    use aoc_runner::{ArcStr, Runner};
-    use std::borrow::Borrow;
-    use std::error::Error;
-    use std::fmt::Display;
-    use std::marker::PhantomData;
+    use crate::{Day8Part2, Factory, YEAR};
+    use aoc_runner::kind::{Generator, OptionKind, PlainKind, ResultKind, Takes};
+    use aoc_runner::{inventory, Answer, ArcStr, Generated, Location, Params, Runner, Solution};
+    const PARAMS: &[(&str, &str)] = &[];
+
     impl Day8Part2 for Factory {
-        fn day8_part2(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>> {
-            Ok(Box::new(RunnerStruct::try_gen(input)?))
+        fn day8_part2(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+            runner(
+                &<crate::aoc_factory::inputs::Day8Part2 as Generator>::generate(input)?,
+                Params::from(PARAMS),
+            )
         }
     }
+
+    inventory::submit! {
+        Solution::new(
+            YEAR,
+            8,
+            2,
+            None,
+            false,
+            <crate::aoc_factory::inputs::Day8Part2 as Generator>::NAME,
+            <crate::aoc_factory::inputs::Day8Part2 as Generator>::ID,
+            Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+            PARAMS,
+            <crate::aoc_factory::inputs::Day8Part2 as Generator>::generate,
+            runner,
+        )
+    }
+    struct __SolverInput;
+
+    impl Takes for __SolverInput {
+        type Input<'a> = Node;
+    }
+    fn runner(
+        generated: &Generated,
+        params: Params,
+    ) -> Result<Box<dyn Runner>, Box<dyn std::error::Error>> {
+        if !<crate::aoc_factory::inputs::Day8Part2 as Generator>::accepts(generated) {
+            return Err("unexpected generator output".into());
+        }
+
+        Ok(Box::new(RunnerStruct {
+            input: generated.clone(),
+            params,
+        }))
+    }
     pub struct RunnerStruct {
-        input: Node,
-        output: PhantomData<Data>,
+        input: Generated,
+        #[allow(dead_code)]
+        params: Params,
     }
     impl Runner for RunnerStruct {
-        fn gen(input: ArcStr) -> Self {
-            Self::try_gen(input).expect("failed to generate input")
-        }
-        fn try_gen(input: ArcStr) -> Result<Self, Box<dyn Error>> {
-            Ok(RunnerStruct {
-                input: parse(input.borrow()).ok_or("generator produce no value")?,
-                output: PhantomData,
-            })
+        fn run(&self) -> Answer {
+            self.try_run().expect("failed to run")
         }
-        fn run(&self) -> Box<dyn Display> {
-            Box::new(part2(self.input.borrow()))
+        fn try_run(&self) -> Result<Answer, Box<dyn std::error::Error>> {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day8Part2, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = <Node>::value(input);
+                    (&output).aoc_kind().answer(output)
+                },
+            )
         }
-        fn bench(&self, black_box: fn(&dyn Display)) {
-            black_box(&part2(self.input.borrow()))
+        fn bench(&self, black_box: fn(&dyn std::fmt::Display)) {
+            aoc_runner::kind::lend::<crate::aoc_factory::inputs::Day8Part2, __SolverInput, _, _>(
+                &self.input,
+                |input| {
+                    let output = <Node>::value(input);
+                    black_box((&output).aoc_kind().display(&output))
+                },
+            )
         }
     }
 }
@@ -137,15 +287,15 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        let root = parse(INPUT).unwrap();
+        let root = Node::parse(INPUT).unwrap();
 
-        assert_eq!(part1(&root), 138);
+        assert_eq!(root.checksum(), 138);
     }
 
     #[test]
     fn part2_example() {
-        let root = parse(INPUT).unwrap();
+        let root = Node::parse(INPUT).unwrap();
 
-        assert_eq!(part2(&root), 66);
+        assert_eq!(root.value(), 66);
     }
 }
@@ -9,257 +9,181 @@ mod day8;
 
 pub use self::aoc_factory::*;
 
+#[allow(unused)]
 mod aoc_factory {
     use aoc_runner::{ArcStr, Runner};
     use std::error::Error;
 
-    pub static YEAR: u32 = 2018u32;
+    #[doc(hidden)]
+    pub const YEAR: u32 = 2018u32;
+
+    // The crates generated by `cargo aoc` run the solutions through it, as they are
+    // registered in this instance of `aoc-runner`, whatever its version or source.
+    // Its items are re-exported rather than the crate, which may be a private `extern crate`
+    #[doc(hidden)]
+    pub mod __aoc_runner {
+        pub use aoc_runner::*;
+    }
+
+    #[doc(hidden)]
     pub struct Factory();
 
+    #[doc(hidden)]
     pub trait Day7Part2 {
         fn day7_part2(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
     }
 
+    #[doc(hidden)]
     pub trait Day2Part1FNV {
         fn day2_part1_fnv(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
     }
 
+    #[doc(hidden)]
     pub trait Day4Part1 {
         fn day4_part1(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
     }
 
+    #[doc(hidden)]
     pub trait Day3Part1 {
         fn day3_part1(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
     }
 
+    #[doc(hidden)]
     pub trait Day1Part1 {
         fn day1_part1(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
     }
 
+    #[doc(hidden)]
     pub trait Day5Part2STACK {
         fn day5_part2_stack(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
     }
 
+    #[doc(hidden)]
     pub trait Day5Part2 {
         fn day5_part2(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
     }
 
+    #[doc(hidden)]
     pub trait Day8Part2 {
         fn day8_part2(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
     }
 
+    #[doc(hidden)]
     pub trait Day2Part1 {
         fn day2_part1(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
     }
 
+    #[doc(hidden)]
     pub trait Day2Part2 {
         fn day2_part2(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
     }
 
+    #[doc(hidden)]
     pub trait Day1Part2 {
         fn day1_part2(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
     }
 
+    #[doc(hidden)]
     pub trait Day3Part2 {
         fn day3_part2(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
     }
 
+    #[doc(hidden)]
     pub trait Day5Part1 {
         fn day5_part1(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
     }
 
+    #[doc(hidden)]
     pub trait Day1Part2FNV {
         fn day1_part2_fnv(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
     }
 
+    #[doc(hidden)]
     pub trait Day4Part2 {
         fn day4_part2(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
     }
 
+    #[doc(hidden)]
     pub trait Day5Part1STACK {
         fn day5_part1_stack(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
     }
 
+    #[doc(hidden)]
+    pub trait Day5Part1REDUCED {
+        fn day5_part1_reduced(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
+    }
+
+    #[doc(hidden)]
+    pub trait Day5Part2REDUCED {
+        fn day5_part2_reduced(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
+    }
+
+    #[doc(hidden)]
     pub trait Day6Part2 {
         fn day6_part2(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
     }
 
+    #[doc(hidden)]
     pub trait Day7Part1 {
         fn day7_part1(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
     }
 
+    #[doc(hidden)]
     pub trait Day8Part1 {
         fn day8_part1(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
     }
 
+    #[doc(hidden)]
     pub trait Day6Part1 {
         fn day6_part1(input: ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
     }
+
+    pub(crate) mod generators {
+        pub struct Day1Part1;
+        pub struct Day1Part2;
+        pub struct Day2Part1;
+        pub struct Day2Part2;
+        pub struct Day3Part1;
+        pub struct Day3Part2;
+        pub struct Day4Part1;
+        pub struct Day4Part2;
+        pub struct Day6Part1;
+        pub struct Day6Part2;
+        pub struct Day7Part1;
+        pub struct Day7Part2;
+        pub struct Day8Part1;
+        pub struct Day8Part2;
+    }
+
+    pub(crate) mod inputs {
+        pub type Day1Part1 = super::generators::Day1Part1;
+        pub type Day1Part2 = super::generators::Day1Part2;
+        pub type Day1Part2FNV = super::generators::Day1Part2;
+        pub type Day2Part1 = super::generators::Day2Part1;
+        pub type Day2Part1FNV = super::generators::Day2Part1;
+        pub type Day2Part2 = super::generators::Day2Part2;
+        pub type Day3Part1 = super::generators::Day3Part1;
+        pub type Day3Part2 = super::generators::Day3Part2;
+        pub type Day4Part1 = super::generators::Day4Part1;
+        pub type Day4Part2 = super::generators::Day4Part2;
+        pub type Day5Part1 = aoc_runner::kind::Raw;
+        pub type Day5Part2 = aoc_runner::kind::Raw;
+        pub type Day5Part1STACK = aoc_runner::kind::Raw;
+        pub type Day5Part2STACK = aoc_runner::kind::Raw;
+        pub type Day5Part1REDUCED = aoc_runner::kind::Raw;
+        pub type Day5Part2REDUCED = aoc_runner::kind::Raw;
+        pub type Day6Part1 = super::generators::Day6Part1;
+        pub type Day6Part2 = super::generators::Day6Part2;
+        pub type Day7Part1 = super::generators::Day7Part1;
+        pub type Day7Part2 = super::generators::Day7Part2;
+        pub type Day8Part1 = super::generators::Day8Part1;
+        pub type Day8Part2 = super::generators::Day8Part2;
+    }
 }
 
 fn main() {
-    use aoc_runner::ArcStr;
-    use std::time::Instant;
-
-    let input_day1 = ArcStr::from(include_str!("../input/2018/day1.txt"));
-    let input_day2 = ArcStr::from(include_str!("../input/2018/day2.txt"));
+    // Inputs are read at runtime, the solutions to run are selected by the arguments
+    let inputs = aoc_runner::Inputs::new(concat!(env!("CARGO_MANIFEST_DIR"), "/input"));
 
-    println!("Advent of code {}\n", YEAR);
-    {
-        let start_time = Instant::now();
-        match Factory::day1_part1(input_day1.clone()) {
-            Ok(runner) => {
-                let inter_time = Instant::now();
-                match runner.try_run() {
-                    Ok(result) => {
-                        let final_time = Instant::now();
-                        println!(
-                            "Day 1 - Part 1: {}\n\tgenerator: {:?},\n\trunner: {:?}\n\n",
-                            result,
-                            (inter_time - start_time),
-                            (final_time - inter_time),
-                        );
-                    }
-                    Err(e) => {
-                        eprintln!("Day 1 - Part 1: FAILED while running:\n{:#?}\n\n", e,);
-                    }
-                }
-            }
-            Err(e) => {
-                eprintln!("Day 1 - Part 1: FAILED while generating:\n{:#?}\n\n", e,);
-            }
-        }
-    }
-    {
-        let start_time = Instant::now();
-        match Factory::day1_part2(input_day1.clone()) {
-            Ok(runner) => {
-                let inter_time = Instant::now();
-                match runner.try_run() {
-                    Ok(result) => {
-                        let final_time = Instant::now();
-                        println!(
-                            "Day 1 - Part 2: {}\n\tgenerator: {:?},\n\trunner: {:?}\n\n",
-                            result,
-                            (inter_time - start_time),
-                            (final_time - inter_time),
-                        );
-                    }
-                    Err(e) => {
-                        eprintln!("Day 1 - Part 2: FAILED while running:\n{:#?}\n\n", e,);
-                    }
-                }
-            }
-            Err(e) => {
-                eprintln!("Day 1 - Part 2: FAILED while generating:\n{:#?}\n\n", e,);
-            }
-        }
-    }
-    {
-        let start_time = Instant::now();
-        match Factory::day1_part2_fnv(input_day1.clone()) {
-            Ok(runner) => {
-                let inter_time = Instant::now();
-                match runner.try_run() {
-                    Ok(result) => {
-                        let final_time = Instant::now();
-                        println!(
-                            "Day 1 - Part 2 - Fnv: {}\n\tgenerator: {:?},\n\trunner: {:?}\n\n",
-                            result,
-                            (inter_time - start_time),
-                            (final_time - inter_time),
-                        );
-                    }
-                    Err(e) => {
-                        eprintln!("Day 1 - Part 2 - Fnv: FAILED while running:\n{:#?}\n\n", e,);
-                    }
-                }
-            }
-            Err(e) => {
-                eprintln!(
-                    "Day 1 - Part 2 - Fnv: FAILED while generating:\n{:#?}\n\n",
-                    e,
-                );
-            }
-        }
-    }
-    {
-        let start_time = Instant::now();
-        match Factory::day2_part1(input_day2.clone()) {
-            Ok(runner) => {
-                let inter_time = Instant::now();
-                match runner.try_run() {
-                    Ok(result) => {
-                        let final_time = Instant::now();
-                        println!(
-                            "Day 2 - Part 1: {}\n\tgenerator: {:?},\n\trunner: {:?}\n\n",
-                            result,
-                            (inter_time - start_time),
-                            (final_time - inter_time),
-                        );
-                    }
-                    Err(e) => {
-                        eprintln!("Day 2 - Part 1: FAILED while running:\n{:#?}\n\n", e,);
-                    }
-                }
-            }
-            Err(e) => {
-                eprintln!("Day 2 - Part 1: FAILED while generating:\n{:#?}\n\n", e,);
-            }
-        }
-    }
-    {
-        let start_time = Instant::now();
-        match Factory::day2_part1_fnv(input_day2.clone()) {
-            Ok(runner) => {
-                let inter_time = Instant::now();
-                match runner.try_run() {
-                    Ok(result) => {
-                        let final_time = Instant::now();
-                        println!(
-                            "Day 2 - Part 1 - Fnv: {}\n\tgenerator: {:?},\n\trunner: {:?}\n\n",
-                            result,
-                            (inter_time - start_time),
-                            (final_time - inter_time),
-                        );
-                    }
-                    Err(e) => {
-                        eprintln!("Day 2 - Part 1 - Fnv: FAILED while running:\n{:#?}\n\n", e,);
-                    }
-                }
-            }
-            Err(e) => {
-                eprintln!(
-                    "Day 2 - Part 1 - Fnv: FAILED while generating:\n{:#?}\n\n",
-                    e,
-                );
-            }
-        }
-    }
-    {
-        let start_time = Instant::now();
-        match Factory::day2_part2(input_day2.clone()) {
-            Ok(runner) => {
-                let inter_time = Instant::now();
-                match runner.try_run() {
-                    Ok(result) => {
-                        let final_time = Instant::now();
-                        println!(
-                            "Day 2 - Part 2: {}\n\tgenerator: {:?},\n\trunner: {:?}\n\n",
-                            result,
-                            (inter_time - start_time),
-                            (final_time - inter_time),
-                        );
-                    }
-                    Err(e) => {
-                        eprintln!("Day 2 - Part 2: FAILED while running:\n{:#?}\n\n", e,);
-                    }
-                }
-            }
-            Err(e) => {
-                eprintln!("Day 2 - Part 2: FAILED while generating:\n{:#?}\n\n", e,);
-            }
-        }
-    }
-    // And so on...
+    aoc_runner::cli::main(YEAR, inputs)
 }
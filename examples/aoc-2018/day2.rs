@@ -1,11 +1,16 @@
-use aoc_runner_derive::aoc;
+use aoc_runner_derive::{aoc, aoc_generator};
 use fnv::FnvHashMap;
 use std::collections::HashMap;
 
+#[aoc_generator(day2)]
+fn parse(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
 #[aoc(day2, part1)]
-fn part1(input: &str) -> u32 {
-    let (nb_double, nb_triple) = input
-        .lines()
+fn part1(ids: &[&str]) -> u32 {
+    let (nb_double, nb_triple) = ids
+        .iter()
         .map(|l| {
             let mut map = HashMap::with_capacity(l.len());
 
@@ -27,9 +32,9 @@ fn part1(input: &str) -> u32 {
 }
 
 #[aoc(day2, part1, Fnv)]
-fn part1_fnv(input: &str) -> u32 {
-    let (nb_double, nb_triple) = input
-        .lines()
+fn part1_fnv(ids: &[&str]) -> u32 {
+    let (nb_double, nb_triple) = ids
+        .iter()
         .map(|l| {
             let mut map = FnvHashMap::default();
             map.reserve(l.len());
@@ -52,11 +57,9 @@ fn part1_fnv(input: &str) -> u32 {
 }
 
 #[aoc(day2, part2)]
-fn part2(input: &str) -> String {
-    let lines = input.lines();
-
-    for (i, l1) in lines.clone().enumerate() {
-        for (_, l2) in lines.clone().enumerate().filter(|&(j, _)| i != j) {
+fn part2(ids: &[&str]) -> String {
+    for (i, l1) in ids.iter().enumerate() {
+        for (_, l2) in ids.iter().enumerate().filter(|&(j, _)| i != j) {
             let filtered: String = l1
                 .chars()
                 .zip(l2.chars())
@@ -81,11 +84,11 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(INPUT_PART1), 12);
+        assert_eq!(part1(&parse(INPUT_PART1)), 12);
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(INPUT_PART2), "fgij");
+        assert_eq!(part2(&parse(INPUT_PART2)), "fgij");
     }
 }
@@ -41,40 +41,6 @@ impl Rectangle {
     }
 }
 
-#[aoc_generator(day3)]
-fn parse(input: &str) -> Result<Vec<Claim>, Box<dyn Error>> {
-    input
-        .lines()
-        .map(|l| {
-            // #123 @ 3,2: 5x4
-            let (id, rect) = l.split_at(l.find('@').ok_or("@ not found")?);
-            let id: u32 = id.get(1..).ok_or("id not found")?.trim().parse()?;
-            let rect = rect.get(1..).ok_or("rect not found")?;
-            let (pos, size) = rect.split_at(rect.find(':').ok_or(": not found")?);
-            let pos = pos.trim();
-
-            let (left, top) = pos.split_at(pos.find(',').ok_or(", not found")?);
-            let left: u32 = left.trim().parse()?;
-            let top: u32 = top.get(1..).ok_or("top not found")?.trim().parse()?;
-
-            let size = size.get(1..).ok_or("size not found")?.trim();
-            let (width, height) = size.split_at(size.find('x').ok_or("x not found")?);
-            let width: u32 = width.trim().parse()?;
-            let height: u32 = height.get(1..).ok_or("height not found")?.trim().parse()?;
-
-            Ok(Claim {
-                id,
-                rect: Rectangle {
-                    left,
-                    top,
-                    width,
-                    height,
-                },
-            })
-        })
-        .collect()
-}
-
 #[aoc(day3, part1)]
 fn part1(claims: &[Claim]) -> usize {
     let mut overlaps = FnvHashSet::default();
@@ -109,6 +75,41 @@ fn part2(claims: &[Claim]) -> Option<u32> {
     })
 }
 
+// Generators can be declared anywhere in the crate, e.g. after their solutions
+#[aoc_generator(day3)]
+fn parse(input: &str) -> Result<Vec<Claim>, Box<dyn Error>> {
+    input
+        .lines()
+        .map(|l| {
+            // #123 @ 3,2: 5x4
+            let (id, rect) = l.split_at(l.find('@').ok_or("@ not found")?);
+            let id: u32 = id.get(1..).ok_or("id not found")?.trim().parse()?;
+            let rect = rect.get(1..).ok_or("rect not found")?;
+            let (pos, size) = rect.split_at(rect.find(':').ok_or(": not found")?);
+            let pos = pos.trim();
+
+            let (left, top) = pos.split_at(pos.find(',').ok_or(", not found")?);
+            let left: u32 = left.trim().parse()?;
+            let top: u32 = top.get(1..).ok_or("top not found")?.trim().parse()?;
+
+            let size = size.get(1..).ok_or("size not found")?.trim();
+            let (width, height) = size.split_at(size.find('x').ok_or("x not found")?);
+            let width: u32 = width.trim().parse()?;
+            let height: u32 = height.get(1..).ok_or("height not found")?.trim().parse()?;
+
+            Ok(Claim {
+                id,
+                rect: Rectangle {
+                    left,
+                    top,
+                    width,
+                    height,
+                },
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
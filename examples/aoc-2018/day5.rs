@@ -33,6 +33,18 @@ fn part2_stack(input: &[u8]) -> Option<usize> {
         .min()
 }
 
+#[aoc(day5, both, Reduced)]
+fn both_reduced(input: &[u8]) -> Option<(usize, usize)> {
+    // Units react the same way in the reduced polymer, so part 2 can start from it
+    let polymer = react(input);
+
+    let shortest = (b'A'..=b'Z')
+        .map(|c| stack(polymer.iter().filter(|&&a| a != c && a != c + DIFF)))
+        .min()?;
+
+    Some((polymer.len(), shortest))
+}
+
 fn reduce<'a>(polymer: impl IntoIterator<Item = &'a u8>) -> usize {
     let polymer: Vec<_> = polymer
         .into_iter()
@@ -87,19 +99,20 @@ fn reduce<'a>(polymer: impl IntoIterator<Item = &'a u8>) -> usize {
 }
 
 fn stack<'a>(polymer: impl IntoIterator<Item = &'a u8>) -> usize {
-    polymer
-        .into_iter()
-        .fold(Vec::new(), |mut stack, &unit| {
-            match stack.last() {
-                Some(&other) if diff(other, unit) == DIFF => {
-                    stack.pop();
-                }
-                _ => stack.push(unit),
+    react(polymer).len()
+}
+
+fn react<'a>(polymer: impl IntoIterator<Item = &'a u8>) -> Vec<u8> {
+    polymer.into_iter().fold(Vec::new(), |mut stack, &unit| {
+        match stack.last() {
+            Some(&other) if diff(other, unit) == DIFF => {
+                stack.pop();
             }
+            _ => stack.push(unit),
+        }
 
-            stack
-        })
-        .len()
+        stack
+    })
 }
 
 #[cfg(test)]
@@ -117,4 +130,9 @@ mod tests {
         assert_eq!(part2(b"dabAcCaCBAcCcaDA"), Some(4));
         assert_eq!(part2_stack(b"dabAcCaCBAcCcaDA"), Some(4));
     }
+
+    #[test]
+    fn both_sample() {
+        assert_eq!(both_reduced(b"dabAcCaCBAcCcaDA"), Some((10, 4)));
+    }
 }
@@ -1,3 +1,4 @@
+use aoc_runner::Params;
 use aoc_runner_derive::{aoc, aoc_generator};
 use petgraph::Direction;
 use petgraph::Graph;
@@ -67,10 +68,8 @@ fn parse(input: &str) -> Result<Graph<Step, ()>, &'static str> {
 }
 
 #[aoc(day7, part1)]
-fn part1(graph: &Graph<Step, ()>) -> Result<String, FromUtf8Error> {
-    let mut remaining = graph.clone();
-
-    let mut seq = Vec::with_capacity(graph.node_count());
+fn part1(mut remaining: Graph<Step, ()>) -> Result<String, FromUtf8Error> {
+    let mut seq = Vec::with_capacity(remaining.node_count());
 
     loop {
         if let Some(i) = remaining
@@ -84,13 +83,11 @@ fn part1(graph: &Graph<Step, ()>) -> Result<String, FromUtf8Error> {
     }
 }
 
-#[aoc(day7, part2)]
-fn part2(graph: &Graph<Step, ()>) -> u32 {
-    part2_internal(graph, 5, 60)
-}
+#[aoc(day7, part2, params(workers = 5, base_time = 60))]
+fn part2(remaining: &mut Graph<Step, ()>, params: &Params) -> u32 {
+    let nb_worker: usize = params.get("workers");
+    let base_time: u32 = params.get("base_time");
 
-fn part2_internal(graph: &Graph<Step, ()>, nb_worker: usize, base_time: u32) -> u32 {
-    let mut remaining = graph.clone();
     let mut workers = vec![(None, 0); nb_worker];
     let mut started = Vec::with_capacity(remaining.node_count());
 
@@ -161,13 +158,15 @@ Step F must be finished before step E can begin.";
     fn part1_example() {
         let graph = parse(INPUT).unwrap();
 
-        assert_eq!(part1(&graph).unwrap(), "CABDFE".to_string());
+        assert_eq!(part1(graph).unwrap(), "CABDFE".to_string());
     }
 
     #[test]
     fn part2_example() {
-        let graph = parse(INPUT).unwrap();
+        let mut graph = parse(INPUT).unwrap();
+
+        let params = Params::new().with("workers", 2).with("base_time", 0);
 
-        assert_eq!(part2_internal(&graph, 2, 0), 15);
+        assert_eq!(part2(&mut graph, &params), 15);
     }
 }
@@ -7,7 +7,13 @@ struct Node {
     metadata: Vec<Data>,
 }
 
+#[aoc]
 impl Node {
+    #[aoc_generator(day8)]
+    fn parse(input: &str) -> Option<Node> {
+        Node::from_iter(&mut input.split_whitespace().map(|s| s.parse().unwrap()))
+    }
+
     fn from_iter<I>(iter: &mut I) -> Option<Node>
     where
         I: Iterator<Item = Data>,
@@ -25,6 +31,7 @@ impl Node {
         Some(Node { children, metadata })
     }
 
+    #[aoc(day8, part1)]
     fn checksum(&self) -> Data {
         let children_sum: Data = self.children.iter().map(|c| c.checksum()).sum();
         let self_sum: Data = self.metadata.iter().sum();
@@ -32,6 +39,7 @@ impl Node {
         children_sum + self_sum
     }
 
+    #[aoc(day8, part2)]
     fn value(&self) -> Data {
         if self.children.is_empty() {
             self.metadata.iter().sum()
@@ -47,21 +55,6 @@ impl Node {
     }
 }
 
-#[aoc_generator(day8)]
-fn parse(input: &str) -> Option<Node> {
-    Node::from_iter(&mut input.split_whitespace().map(|s| s.parse().unwrap()))
-}
-
-#[aoc(day8, part1)]
-fn part1(root: &Node) -> Data {
-    root.checksum()
-}
-
-#[aoc(day8, part2)]
-fn part2(root: &Node) -> Data {
-    root.value()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,15 +63,15 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        let root = parse(INPUT).unwrap();
+        let root = Node::parse(INPUT).unwrap();
 
-        assert_eq!(part1(&root), 138);
+        assert_eq!(root.checksum(), 138);
     }
 
     #[test]
     fn part2_example() {
-        let root = parse(INPUT).unwrap();
+        let root = Node::parse(INPUT).unwrap();
 
-        assert_eq!(part2(&root), 66);
+        assert_eq!(root.value(), 66);
     }
 }
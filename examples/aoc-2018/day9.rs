@@ -0,0 +1,111 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+
+/// The words describing a game, e.g. `10 players; last marble is worth 1618 points`.
+struct Words<'a>(Vec<&'a str>);
+
+#[aoc]
+impl<'a> Words<'a> {
+    // Generators of types with lifetimes may borrow from the input
+    #[aoc_generator(day9)]
+    fn parse(input: &'a str) -> Words<'a> {
+        Words(input.split_whitespace().collect())
+    }
+
+    fn rules(&self) -> Option<(u32, u32)> {
+        let players = self.0.first()?.parse().ok()?;
+        let last_marble = self.0.get(6)?.parse().ok()?;
+
+        Some((players, last_marble))
+    }
+}
+
+/// A circle of marbles, as the neighbours of each of them.
+struct Circle<T> {
+    clockwise: Vec<T>,
+    counter_clockwise: Vec<T>,
+}
+
+#[aoc]
+impl Circle<u32> {
+    fn high_score(players: u32, last_marble: u32) -> u64 {
+        let mut circle = Circle {
+            clockwise: vec![0; last_marble as usize + 1],
+            counter_clockwise: vec![0; last_marble as usize + 1],
+        };
+        let mut scores = vec![0u64; players as usize];
+        let mut current = 0;
+
+        for marble in 1..=last_marble {
+            if marble % 23 == 0 {
+                for _ in 0..7 {
+                    current = circle.counter_clockwise[current as usize];
+                }
+                let next = circle.remove(current);
+
+                scores[(marble % players) as usize] += u64::from(marble + current);
+                current = next;
+            } else {
+                let after = circle.clockwise[current as usize];
+                circle.insert_after(after, marble);
+                current = marble;
+            }
+        }
+
+        scores.into_iter().max().unwrap_or(0)
+    }
+
+    fn insert_after(&mut self, after: u32, marble: u32) {
+        let before = self.clockwise[after as usize];
+
+        self.clockwise[after as usize] = marble;
+        self.counter_clockwise[before as usize] = marble;
+        self.clockwise[marble as usize] = before;
+        self.counter_clockwise[marble as usize] = after;
+    }
+
+    /// Removes `marble`, returning the one clockwise of it.
+    fn remove(&mut self, marble: u32) -> u32 {
+        let before = self.counter_clockwise[marble as usize];
+        let after = self.clockwise[marble as usize];
+
+        self.clockwise[before as usize] = after;
+        self.counter_clockwise[after as usize] = before;
+
+        after
+    }
+
+    #[aoc(day9, part1)]
+    fn part1(words: &Words<'_>) -> Option<u64> {
+        let (players, last_marble) = words.rules()?;
+
+        Some(Circle::high_score(players, last_marble))
+    }
+
+    #[aoc(day9, part2)]
+    fn part2(words: &Words<'_>) -> Option<u64> {
+        let (players, last_marble) = words.rules()?;
+
+        Some(Circle::high_score(players, last_marble * 100))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_examples() {
+        let examples = [
+            ("9 players; last marble is worth 25 points", 32),
+            ("10 players; last marble is worth 1618 points", 8317),
+            ("13 players; last marble is worth 7999 points", 146373),
+            ("17 players; last marble is worth 1104 points", 2764),
+            ("21 players; last marble is worth 6111 points", 54718),
+            ("30 players; last marble is worth 5807 points", 37305),
+        ];
+
+        for (input, score) in examples.iter() {
+            assert_eq!(Circle::part1(&Words::parse(input)), Some(*score));
+        }
+    }
+}
@@ -8,5 +8,6 @@ mod day5;
 mod day6;
 mod day7;
 mod day8;
+mod day9;
 
 aoc_lib! { year = 2018 }